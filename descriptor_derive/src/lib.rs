@@ -1,21 +1,74 @@
 use convert_case::{Case, Casing};
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use proc_macro_error::{abort, proc_macro_error};
-use quote::quote;
-use syn::{parse_macro_input, Fields, Ident, Item, ItemEnum, ItemStruct, Type, TypePath};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Expr, ExprLit, Field, Fields, GenericArgument, GenericParam,
+    Generics, Ident, Index, Item, ItemEnum, ItemStruct, Lit, Member, PathArguments, Type, TypePath,
+};
 
-use crate::parse::{DescriptorFieldAttr, DescriptorStructAttr};
+use crate::parse::{DescriptorFieldAttr, DescriptorStructAttr, Transform};
 
 mod parse;
 
 #[derive(Clone)]
 struct StructField {
+    // The binding used in generated code, `field_0` for unnamed fields
     ident: Ident,
+    // The member used to access the field on self, `self.0` for unnamed fields
+    member: Member,
+    // An expression giving a reference to the field, `&self.0` for structs or the binding for enums
+    access: TokenStream,
     typ: Type,
     field_name: String,
     attr: DescriptorFieldAttr,
 }
 
+impl StructField {
+    // The type used to describe the field, after conversion by attributes
+    fn described_type(&self) -> TokenStream {
+        if self.attr.duration_between.is_some()
+            || self.attr.date_format.is_some()
+            || self.attr.display
+            || self.attr.thousands.is_some()
+            || self.attr.precision.is_some()
+            || self.attr.bool_labels.is_some()
+            || self.attr.debug
+            || self.attr.show_sign.is_some()
+            || !self.attr.pipe.is_empty()
+            || self.attr.max_width.is_some()
+            || self.attr.wrap.is_some()
+            || self.attr.color.is_some()
+        {
+            quote!(String)
+        } else if let Some(into) = &self.attr.into {
+            quote!(#into)
+        } else {
+            let typ = &self.typ;
+            quote!(#typ)
+        }
+    }
+
+    // The title displayed in the description
+    fn title_name(&self) -> String {
+        if let Some(rename) = self.attr.rename.as_ref().or(self.attr.doc.as_ref()) {
+            return rename.clone();
+        }
+        match &self.member {
+            Member::Named(_) => self.field_name.to_case(Case::Title),
+            Member::Unnamed(_) => format!("Field {}", self.field_name),
+        }
+    }
+
+    // Prepended to the titles of a struct flattened with a `prefix`
+    fn title_prefix(&self) -> String {
+        match &self.attr.prefix {
+            Some(prefix) => format!("{} ", prefix.to_case(Case::Title)),
+            None => String::new(),
+        }
+    }
+}
+
 #[proc_macro_derive(Descriptor, attributes(descriptor))]
 #[proc_macro_error]
 pub fn descriptor(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -36,6 +89,7 @@ pub fn descriptor(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// `default_headers` will return all headers by default, or just the one provided by the user
 /// `headers` will generate the list of header recursively
 /// `header_name` a method to get an header overrided name
+/// `machine_key` a method to get the key of an header in machine readable exports
 /// `struct_pad` internal method in order to get the padding to apply for fields
 /// `describe` will call describe on all fields in order to decriptor the description.
 /// ```
@@ -43,33 +97,77 @@ fn generate_struct_decriptor(input: ItemStruct) -> proc_macro::TokenStream {
     let name = &input.ident;
 
     let decriptor_struct_attributes = parse::extract_struct_attributes(&input.attrs);
-    let fields = extract_field(&input);
+    if decriptor_struct_attributes.transparent {
+        return generate_transparent_decriptor(input);
+    }
+    let mut fields = extract_field(&input);
+    fields.extend(computed_fields(&decriptor_struct_attributes));
 
     let describe = describe_method_for_struct(&fields, &decriptor_struct_attributes);
     let default_headers = default_headers_for_struct(&fields, &decriptor_struct_attributes);
     let headers = headers_for_struct(&fields, &decriptor_struct_attributes);
     let header_name_func = rename_headers_for_struct(&fields, &decriptor_struct_attributes);
-    let to_field = to_field_for_struct(&fields, &decriptor_struct_attributes);
+    let machine_key = machine_keys_for_struct(&fields, &decriptor_struct_attributes);
+    let min_width = min_widths_for_struct(&fields);
+    let to_field_with_null = to_field_for_struct(&fields, &decriptor_struct_attributes);
     let pad_struct = pad_struct(&fields);
 
     generate_trait(
         name,
-        describe,
-        to_field,
-        Some(pad_struct),
-        Some(default_headers),
-        Some(headers),
-        Some(header_name_func),
+        &input.generics,
+        TraitMethods {
+            describe,
+            to_field: quote! {
+                self.to_field_with_null(field_name, descriptor::NULL_PLACEHOLDER)
+            },
+            to_field_with_null: Some(to_field_with_null),
+            pad: Some(pad_struct),
+            default_headers: Some(default_headers),
+            headers: Some(headers),
+            header_name: Some(header_name_func),
+            machine_key: Some(machine_key),
+            min_width: Some(min_width),
+        },
+    )
+    .into()
+}
+
+// A transparent struct forwards every method to its single field
+fn generate_transparent_decriptor(input: ItemStruct) -> proc_macro::TokenStream {
+    let fields = extract_field(&input);
+    let field = match fields.as_slice() {
+        [field] => field,
+        _ => abort! {input.ident, "`transparent` requires a struct with exactly one field"},
+    };
+    let typ = &field.typ;
+    let access = &field.access;
+
+    generate_trait(
+        &input.ident,
+        &input.generics,
+        TraitMethods {
+            describe: quote! {
+                descriptor::Describe::describe(#access, writer, ctx)
+            },
+            to_field: quote! {
+                descriptor::Describe::to_field(#access, field_name)
+            },
+            to_field_with_null: Some(quote! {
+                descriptor::Describe::to_field_with_null(#access, field_name, null_placeholder)
+            }),
+            pad: Some(quote!(<#typ>::struct_pad())),
+            default_headers: Some(quote!(<#typ>::default_headers())),
+            headers: Some(quote!(<#typ>::headers())),
+            header_name: Some(quote!(<#typ>::header_name(header))),
+            machine_key: Some(quote!(<#typ>::machine_key(header))),
+            min_width: Some(quote!(<#typ>::min_width(header))),
+        },
     )
     .into()
 }
 
 fn pad_struct(fields: &[StructField]) -> TokenStream {
-    let pad = match fields
-        .iter()
-        .map(|field| field.field_name.to_case(Case::Title).len())
-        .max()
-    {
+    let pad = match fields.iter().map(|field| field.title_name().len()).max() {
         None => 0,
         Some(x) => x + 1,
     };
@@ -81,8 +179,9 @@ fn pad_struct(fields: &[StructField]) -> TokenStream {
     for field in fields {
         if field.attr.flatten {
             let typ = &field.typ;
+            let prefix_len = field.title_prefix().len();
             max_pad.extend(quote! {
-                let pad = pad.max(#typ::struct_pad());
+                let pad = pad.max(<#typ>::struct_pad() + #prefix_len);
             })
         }
     }
@@ -93,32 +192,116 @@ fn pad_struct(fields: &[StructField]) -> TokenStream {
     }
 }
 
+// Fields with an `order` are placed in that slot among the described fields,
+// the other fields fill the remaining slots in declaration order
 fn extract_field(input: &ItemStruct) -> Vec<StructField> {
-    match &input.fields {
+    let (mut ordered, unordered): (Vec<_>, Vec<_>) = fields_of(&input.fields, true)
+        .into_iter()
+        .filter(|x| !x.attr.skip)
+        .partition(|x| x.attr.order.is_some());
+    ordered.sort_by_key(|x| x.attr.order);
+
+    let mut ordered = ordered.into_iter().peekable();
+    let mut unordered = unordered.into_iter().peekable();
+    let mut fields = vec![];
+    while let Some(slot) = ordered.peek().and_then(|x| x.attr.order) {
+        match unordered.peek() {
+            Some(_) if slot > fields.len() => fields.extend(unordered.next()),
+            _ => fields.extend(ordered.next()),
+        }
+    }
+    fields.extend(unordered);
+    fields
+}
+
+// Fields computed from other fields, described after the fields of the struct
+fn computed_fields(struct_attributes: &DescriptorStructAttr) -> Vec<StructField> {
+    struct_attributes
+        .duration_between
+        .iter()
+        .map(|(name, start, end)| {
+            let ident = Ident::new(name, Span::call_site());
+            let mut attr = parse::extract_field_attributes(&[]);
+            attr.duration_between = Some((start.clone(), end.clone()));
+            StructField {
+                member: Member::Named(ident.clone()),
+                ident,
+                access: quote!(()),
+                typ: parse_quote!(()),
+                field_name: name.clone(),
+                attr,
+            }
+        })
+        .collect()
+}
+
+// Marker fields like `PhantomData` are always skipped
+fn field_attributes(field: &Field) -> DescriptorFieldAttr {
+    let mut attr = parse::extract_field_attributes(&field.attrs);
+    if path_is_phantom_data(&field.ty) {
+        attr.skip = true;
+    }
+    attr
+}
+
+// List all fields, including skipped ones, accessed from self or through a match binding
+fn fields_of(fields: &Fields, from_self: bool) -> Vec<StructField> {
+    let access = |ident: &Ident, member: &Member| {
+        if from_self {
+            quote!(&self.#member)
+        } else {
+            quote!(#ident)
+        }
+    };
+
+    match fields {
         Fields::Named(named) => named
             .named
             .iter()
             .map(|field| {
                 let ident = match field.ident.as_ref() {
-                    None => abort! {input.ident, "no identifier on field"},
+                    None => abort! {field, "no identifier on field"},
                     Some(ident) => ident,
                 };
+                let member = Member::Named(ident.clone());
 
                 StructField {
                     ident: ident.clone(),
+                    access: access(ident, &member),
+                    member,
                     typ: field.ty.clone(),
                     field_name: ident.to_string(),
-                    attr: parse::extract_field_attributes(&field.attrs),
+                    attr: field_attributes(field),
+                }
+            })
+            .collect::<Vec<StructField>>(),
+        Fields::Unnamed(unnamed) => unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let ident = format_ident!("field_{}", i);
+                let member = Member::Unnamed(Index::from(i));
+
+                StructField {
+                    access: access(&ident, &member),
+                    ident,
+                    member,
+                    typ: field.ty.clone(),
+                    field_name: i.to_string(),
+                    attr: field_attributes(field),
                 }
             })
-            .filter(|x| !x.attr.skip)
             .collect::<Vec<StructField>>(),
-        _ => abort! {input.ident, "not implemented for unnamed struct"},
+        Fields::Unit => vec![],
     }
 }
 
-// Generate the to_field method implementation for the struct
-fn to_field_for_struct(fields: &[StructField], struct_attributes: &DescriptorStructAttr) -> TokenStream {
+// Generate the to_field_with_null method implementation for the struct
+fn to_field_for_struct(
+    fields: &[StructField],
+    struct_attributes: &DescriptorStructAttr,
+) -> TokenStream {
     let mut match_to_field = quote!();
 
     fields
@@ -127,22 +310,73 @@ fn to_field_for_struct(fields: &[StructField], struct_attributes: &DescriptorStr
             let field_name = &field.field_name;
 
             let value = field_getter(
-                &field,
+                field,
                 quote! {
-                    to_field(_child)
+                    to_field_with_null(_child, null_placeholder)
                 },
             );
 
-            quote! {
-                #field_name => {#value},
+            if field.attr.json_inline {
+                let access = &field.access;
+                quote! {
+                    #field_name => descriptor::json_inline!(#access),
+                }
+            } else if let Some(join) = &field.attr.join {
+                let access = &field.access;
+                quote! {
+                    #field_name => (#access)
+                        .iter()
+                        .map(|x| x.to_field_with_null(_child, null_placeholder))
+                        .collect::<Vec<_>>()
+                        .join(#join),
+                }
+            } else if field.attr.flatten_option {
+                quote! {
+                    #field_name => {#value},
+                }
+            } else {
+                // The whole optional struct is rendered in a single column
+                let access = &field.access;
+                quote! {
+                    #field_name if _child.is_empty() => match #access {
+                        Some(value) => descriptor::object_describe_oneline_to_string(value)
+                            .unwrap_or_default(),
+                        None => null_placeholder.to_string(),
+                    },
+                    #field_name => {#value},
+                }
             }
         })
         .for_each(|ts| match_to_field.extend(ts));
 
+    for (field, prefix) in flattened_fields(fields) {
+        let typ = field.described_type();
+        let access = &field.access;
+        let prefix_len = prefix.len();
+        match_to_field.extend(quote! {
+            _ if descriptor::flattened_header::<#typ>(field_name, #prefix).is_some() => {
+                (#access).to_field_with_null(&field_name[#prefix_len..], null_placeholder)
+            },
+        });
+    }
+
+    for field in fields.iter() {
+        let access = &field.access;
+        for i in 0..field.attr.spread.unwrap_or_default() {
+            let header = format!("{}_{}", field.field_name, i + 1);
+            match_to_field.extend(quote! {
+                #header => (#access)
+                    .get(#i)
+                    .map(|x| x.to_field_with_null(_child, null_placeholder))
+                    .unwrap_or_else(|| null_placeholder.to_string()),
+            });
+        }
+    }
+
     let fallback = if let Some(extra_fields) = &struct_attributes.extra_fields {
         quote! {
             _ => {
-                Into::<#extra_fields>::into(self).to_field(field_name)
+                Into::<#extra_fields>::into(self).to_field_with_null(field_name, null_placeholder)
             },
         }
     } else {
@@ -166,6 +400,8 @@ fn to_field_for_struct(fields: &[StructField], struct_attributes: &DescriptorStr
 
         let value = match field {
             #match_to_field
+            // The whole struct in a single cell, e.g. where a recursive type is cut
+            "" => descriptor::object_describe_oneline_to_string(self).unwrap_or_default(),
             #fallback
         };
 
@@ -175,6 +411,14 @@ fn to_field_for_struct(fields: &[StructField], struct_attributes: &DescriptorStr
     func
 }
 
+// The flattened fields with their prefix, their headers are matched on the whole header
+fn flattened_fields(fields: &[StructField]) -> impl Iterator<Item = (&StructField, &str)> {
+    fields
+        .iter()
+        .filter(|field| field.attr.flatten)
+        .map(|field| (field, field.attr.prefix.as_deref().unwrap_or_default()))
+}
+
 // Generate the rename_header method implementation for the struct
 fn rename_headers_for_struct(
     fields: &[StructField],
@@ -185,28 +429,51 @@ fn rename_headers_for_struct(
     fields
         .iter()
         .map(|field| {
-            let typ = &field.typ;
             let field_name = &field.field_name;
 
-            match &field.attr.rename_header {
-                Some(rename) => quote! {
+            match (&field.attr.rename_header, &field.attr.doc) {
+                (Some(rename), _) => quote! {
                     #field_name => Some(#rename.to_string()),
                 },
-                None => {
-                    if let Some(into) = &field.attr.into {
-                        quote! {
-                            #field_name => <#into>::header_name(_child),
-                        }
-                    } else {
-                        quote! {
-                            #field_name => <#typ>::header_name(_child),
-                        }
+                // The function is called with the field name, e.g. to translate it
+                _ if field.attr.rename_header_fn.is_some() => {
+                    let func = &field.attr.rename_header_fn;
+                    let typ = field.described_type();
+                    quote! {
+                        #field_name if _child.is_empty() => Some(#func(#field_name)),
+                        #field_name => <#typ>::header_name(_child),
+                    }
+                }
+                // The doc only labels the field itself, not the headers of its children
+                (None, Some(doc)) => {
+                    let typ = field.described_type();
+                    quote! {
+                        #field_name if _child.is_empty() => Some(#doc.to_string()),
+                        #field_name => <#typ>::header_name(_child),
+                    }
+                }
+                (None, None) => {
+                    let typ = field.described_type();
+                    quote! {
+                        #field_name => <#typ>::header_name(_child),
                     }
                 }
             }
         })
         .for_each(|ts| rename_headers.extend(ts));
 
+    for (field, prefix) in flattened_fields(fields) {
+        let typ = field.described_type();
+        let prefix_len = prefix.len();
+        let label_prefix = prefix.to_uppercase();
+        rename_headers.extend(quote! {
+            _ if descriptor::flattened_header::<#typ>(header, #prefix).is_some() => {
+                <#typ>::header_name(&header[#prefix_len..])
+                    .map(|name| format!("{}{}", #label_prefix, name))
+            },
+        });
+    }
+
     if let Some(extra_fields) = &struct_attributes.extra_fields {
         rename_headers.extend(quote! {
             stringify!(#extra_fields) => <#extra_fields>::header_name(_child),
@@ -214,8 +481,8 @@ fn rename_headers_for_struct(
     }
 
     let func = quote! {
-        let (header, _child) = descriptor::get_keys(header);
-        match header {
+        let (field, _child) = descriptor::get_keys(header);
+        match field {
             #rename_headers
             _ => None,
         }
@@ -223,8 +490,116 @@ fn rename_headers_for_struct(
     func
 }
 
+// Generate the machine_key method, nested keys are joined with a dot
+fn machine_keys_for_struct(
+    fields: &[StructField],
+    struct_attributes: &DescriptorStructAttr,
+) -> TokenStream {
+    let mut machine_keys = quote!();
+
+    fields
+        .iter()
+        .map(|field| {
+            let field_name = &field.field_name;
+            let typ = field.described_type();
+
+            match &field.attr.key {
+                Some(key) => quote! {
+                    #field_name if _child.is_empty() => Some(#key.to_string()),
+                    #field_name => Some(format!(
+                        "{}.{}",
+                        #key,
+                        <#typ>::machine_key(_child).unwrap_or_else(|| _child.to_string())
+                    )),
+                },
+                None => quote! {
+                    #field_name if _child.is_empty() => None,
+                    #field_name => <#typ>::machine_key(_child)
+                        .map(|key| format!("{}.{}", #field_name, key)),
+                },
+            }
+        })
+        .for_each(|ts| machine_keys.extend(ts));
+
+    for (field, prefix) in flattened_fields(fields) {
+        let typ = field.described_type();
+        let prefix_len = prefix.len();
+        machine_keys.extend(quote! {
+            _ if descriptor::flattened_header::<#typ>(header, #prefix).is_some() => {
+                <#typ>::machine_key(&header[#prefix_len..])
+                    .map(|key| format!("{}{}", #prefix, key))
+            },
+        });
+    }
+
+    if let Some(extra_fields) = &struct_attributes.extra_fields {
+        machine_keys.extend(quote! {
+            stringify!(#extra_fields) => <#extra_fields>::machine_key(_child),
+        });
+    }
+
+    quote! {
+        let (field, _child) = descriptor::get_keys(header);
+        match field {
+            #machine_keys
+            _ => None,
+        }
+    }
+}
+
+// Generate the min_width method, nested headers get the minimum of their own field
+fn min_widths_for_struct(fields: &[StructField]) -> TokenStream {
+    let mut min_widths = quote!();
+
+    for field in fields {
+        let field_name = &field.field_name;
+        let typ = field.described_type();
+        let min_width = field.attr.min_width.unwrap_or_default();
+
+        // A literal `max_width` is checked at compile time
+        if let (
+            Some(min_width),
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Int(max_width),
+                ..
+            })),
+        ) = (field.attr.min_width, &field.attr.max_width)
+        {
+            if matches!(max_width.base10_parse::<usize>(), Ok(max_width) if min_width > max_width) {
+                abort! {field.ident, "`min_width` can not be greater than `max_width`"}
+            }
+        }
+
+        min_widths.extend(quote! {
+            #field_name if _child.is_empty() => #min_width,
+            #field_name => <#typ>::min_width(_child),
+        });
+    }
+
+    for (field, prefix) in flattened_fields(fields) {
+        let typ = field.described_type();
+        let prefix_len = prefix.len();
+        min_widths.extend(quote! {
+            _ if descriptor::flattened_header::<#typ>(header, #prefix).is_some() => {
+                <#typ>::min_width(&header[#prefix_len..])
+            },
+        });
+    }
+
+    quote! {
+        let (field, _child) = descriptor::get_keys(header);
+        match field {
+            #min_widths
+            _ => 0,
+        }
+    }
+}
+
 // Will generate the header function, we list all possible fields recursively
-fn headers_for_struct(fields: &[StructField], struct_attributes: &DescriptorStructAttr) -> TokenStream {
+fn headers_for_struct(
+    fields: &[StructField],
+    struct_attributes: &DescriptorStructAttr,
+) -> TokenStream {
     let mut headers = quote! {
         let mut headers = Vec::new();
     };
@@ -233,19 +608,47 @@ fn headers_for_struct(fields: &[StructField], struct_attributes: &DescriptorStru
         let typ = &field.typ;
         let field_name = &field.field_name;
 
-        headers.extend(if let Some(into) = &field.attr.into {
-            quote! {
-                let mut fields = <#into>::default_headers()
+        // One column per element, up to the cap
+        if let Some(spread) = field.attr.spread {
+            if !path_is_vec(typ) {
+                abort! {field.ident, "`spread` can only be used on a `Vec` field"}
             }
-        } else {
-            quote! {
-                let mut fields = <#typ>::default_headers()
+            for i in 1..=spread {
+                let header = format!("{}_{}", field_name, i);
+                headers.extend(quote! {
+                    headers.push(#header.to_string());
+                });
+            }
+            continue;
+        }
+
+        if !field.attr.flatten_option {
+            if !path_is_option(typ) {
+                abort! {field.ident, "`flatten_option` can only be used on an `Option` field"}
             }
+            headers.extend(quote! {
+                headers.push(#field_name.to_string());
+            });
+            continue;
+        }
+
+        let described_type = field.described_type();
+        headers.extend(quote! {
+            let mut fields = <#described_type>::default_headers()
         });
 
+        // Flattened headers are spliced in, behind their prefix if any
+        let header = match (&field.attr.prefix, field.attr.flatten) {
+            (Some(prefix), true) => quote!(format!("{}{}", #prefix, x)),
+            (None, true) => quote!(x),
+            (Some(_), false) => {
+                abort! {field.ident, "`prefix` can only be used with `flatten`"}
+            }
+            (None, false) => quote!(format!("{}.{}", #field_name, x)),
+        };
         headers.extend(quote! {
                 .into_iter()
-                .map(|x| format!("{}.{}", #field_name, x).to_string())
+                .map(|x| #header)
                 .collect::<Vec<String>>();
 
                 if fields.is_empty() {
@@ -268,7 +671,12 @@ fn headers_for_struct(fields: &[StructField], struct_attributes: &DescriptorStru
         headers
     });
 
-    headers
+    // A recursive type is listed as a single column where it appears again
+    quote! {
+        descriptor::headers_guard::<Self, _>(|| {
+            #headers
+        })
+    }
 }
 
 // Generate the default_headers method implementation for the struct
@@ -320,17 +728,55 @@ fn describe_method_for_struct(
         }
         None => {
             let mut describe = quote!();
+            let mut pad = quote!(Self::struct_pad());
+
+            // Fields mark the line break as written for the next ones
+            if fields.iter().any(|x| !x.attr.skip_description) {
+                describe.extend(quote! {
+                    let mut ctx = ctx;
+                });
+            }
+
+            // Fields that may be omitted only widen the titles when they are written
+            let skippable =
+                |field: &StructField| field.attr.skip_if_none || field.attr.skip_if_empty;
+            if fields.iter().any(skippable) {
+                let always = fields
+                    .iter()
+                    .filter(|field| !skippable(field))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let always_pad = pad_struct(&always);
+                describe.extend(quote! {
+                    let mut pad = { #always_pad };
+                });
+                for field in fields.iter().filter(|field| skippable(field)) {
+                    let access = &field.access;
+                    let title_pad = field.title_name().len() + 1;
+                    let written = if field.attr.skip_if_none {
+                        quote!((#access).is_some())
+                    } else {
+                        quote!(!(#access).is_empty())
+                    };
+                    describe.extend(quote! {
+                        if #written {
+                            pad = pad.max(#title_pad);
+                        }
+                    });
+                }
+                pad = quote!(pad);
+            }
 
             fields
                 .iter()
                 .filter(|x| !x.attr.skip_description)
                 .enumerate()
-                .map(|(i, x)| describe_field(x, i == 0))
+                .map(|(i, x)| describe_field(x, i == 0, &pad))
                 .for_each(|value| describe.extend(value));
 
             if let Some(extra_fields) = &struct_attributes.extra_fields {
                 describe.extend(quote! {
-                    Into::<#extra_fields>::into(self).describe(writer, ctx.pad(Self::struct_pad()))?;
+                    Into::<#extra_fields>::into(self).describe(writer, ctx.pad(#pad))?;
                 })
             }
 
@@ -341,28 +787,91 @@ fn describe_method_for_struct(
 }
 
 // Will generate the describe for a specific field
-fn describe_field(field: &StructField, first_field: bool) -> TokenStream {
-    let title_name = field.field_name.to_case(Case::Title);
-    let ident = &field.ident;
+fn describe_field(field: &StructField, first_field: bool, pad: &TokenStream) -> TokenStream {
+    let describe = describe_field_value(field, first_field, pad);
+    // Only the first written field may skip its line break
+    let describe = quote! {
+        #describe
+        ctx.leading_newline = true;
+    };
+    let access = &field.access;
+
+    if field.attr.skip_if_none {
+        if !path_is_option(&field.typ) {
+            abort! {field.ident, "`skip_if_none` can only be used on an `Option` field"}
+        }
+        // The whole field, title included, is omitted
+        quote! {
+            if (#access).is_some() {
+                #describe
+            }
+        }
+    } else if field.attr.skip_if_empty {
+        if !path_is_collection(&field.typ) {
+            abort! {field.ident, "`skip_if_empty` can only be used on a `String` or a collection field"}
+        }
+        quote! {
+            if !(#access).is_empty() {
+                #describe
+            }
+        }
+    } else {
+        describe
+    }
+}
+
+fn describe_field_value(field: &StructField, first_field: bool, pad: &TokenStream) -> TokenStream {
+    let title_name = field.title_name();
+    let access = &field.access;
+
+    let title_prefix = field.title_prefix();
 
-    if field.attr.flatten {
+    if field.attr.flatten && path_is_option(&field.typ) {
+        // A missing struct is rendered as a regular field
+        quote! {
+            match #access {
+                Some(value) => value.describe(writer, ctx.pad(#pad).with_title_prefix(#title_prefix))?,
+                None => {
+                    ctx.write_title(writer, #title_name, #first_field)?;
+                    ctx.indent(#pad, #title_name.len()).write_null(writer)?;
+                }
+            }
+        }
+    } else if field.attr.flatten {
         quote! {
-            self.#ident.describe(writer, ctx.pad(Self::struct_pad()))?;
+            (#access).describe(writer, ctx.pad(#pad).with_title_prefix(#title_prefix))?;
         }
     } else {
         let title = quote! {
             ctx.write_title(writer, #title_name, #first_field)?;
         };
 
-        let value = if field.attr.output_table {
+        let value = if let Some(headers) = &field.attr.output_table_headers {
+            quote! {
+                ctx.describe_table_with_header(
+                    #access,
+                    &#headers.iter().map(|x| x.to_string()).collect::<Vec<String>>(),
+                    writer,
+                )?;
+            }
+        } else if field.attr.output_table {
             quote! {
-                ctx.describe_table(&self.#ident, writer)?;
+                ctx.describe_table(#access, writer)?;
             }
         } else {
+            let numbered = field.attr.numbered;
+            // The type given to `map` or `into` is not known here
+            if numbered
+                && field.attr.map.is_none()
+                && field.attr.into.is_none()
+                && !path_is_list(&field.typ)
+            {
+                abort! {field.ident, "`numbered` can only be used on a list field like a `Vec`"}
+            }
             field_getter(
-                &field,
+                field,
                 quote! {
-                    describe(writer, ctx.indent(Self::struct_pad(), #title_name.len()))?;
+                    describe(writer, ctx.indent(#pad, #title_name.len()).with_numbered(#numbered))?;
                 },
             )
         };
@@ -377,14 +886,189 @@ fn describe_field(field: &StructField, first_field: bool) -> TokenStream {
 // A helper function that handle all the code to map/into/resolve_option
 // Need a method to call after the getter
 fn field_getter(field: &StructField, method: TokenStream) -> TokenStream {
+    let access = &field.access;
+
+    let value = if field.attr.pipe.is_empty()
+        && field.attr.max_width.is_none()
+        && field.attr.wrap.is_none()
+        && field.attr.color.is_none()
+    {
+        field_value(field, method.clone())
+    } else {
+        piped_value(field, method.clone())
+    };
+
+    let value = if let Some(empty) = &field.attr.empty {
+        quote! {
+            if (#access).is_empty() {
+                #empty.to_string().#method
+            } else {
+                #value
+            }
+        }
+    } else {
+        value
+    };
+
+    // Missing values show the field default instead of the null placeholder
+    if let Some(default) = &field.attr.default {
+        if field.attr.empty.is_some() {
+            abort! {field.ident, "`default` can not be used with `empty`"}
+        }
+        let missing = if path_is_option(&field.typ) {
+            quote!((#access).is_none())
+        } else if path_is_collection(&field.typ) {
+            quote!((#access).is_empty())
+        } else {
+            abort! {field.ident, "`default` can only be used on an `Option`, a `String` or a collection field"}
+        };
+        quote! {
+            if #missing {
+                #default.to_string().#method
+            } else {
+                #value
+            }
+        }
+    } else {
+        value
+    }
+}
+
+// Apply the `pipe(...)` transforms in order to the rendered field,
+// then truncate it to `max_width`, wrap it and call the `color` function
+fn piped_value(field: &StructField, method: TokenStream) -> TokenStream {
+    let value = field_value(field, quote!(to_field("")));
+    let mut piped = quote! {
+        let value = { #value };
+    };
+
+    for transform in &field.attr.pipe {
+        piped.extend(match transform {
+            Transform::Uppercase => quote!(let value = value.to_uppercase();),
+            Transform::Lowercase => quote!(let value = value.to_lowercase();),
+            Transform::Prefix(prefix) => quote!(let value = format!("{}{}", #prefix, value);),
+            Transform::Suffix(suffix) => quote!(let value = format!("{}{}", value, #suffix);),
+        });
+    }
+
+    if let Some(max_width) = &field.attr.max_width {
+        piped.extend(quote!(let value = descriptor::truncate(&value, #max_width);));
+    }
+
+    if let Some(wrap) = &field.attr.wrap {
+        piped.extend(quote!(let value = descriptor::wrap(&value, #wrap);));
+    }
+
+    if let Some(color) = &field.attr.color {
+        piped.extend(quote!(let value: String = #color(&value);));
+    }
+
+    quote! {
+        #piped
+        value.#method
+    }
+}
+
+// The value of the field, once the empty collection case is handled
+fn field_value(field: &StructField, method: TokenStream) -> TokenStream {
     let ident = &field.ident;
+    let access = &field.access;
+
+    if let Some((start, end)) = &field.attr.duration_between {
+        let start = Ident::new(start, Span::call_site());
+        let end = Ident::new(end, Span::call_site());
+        return quote! {
+            descriptor::duration_between(&self.#start, &self.#end).#method
+        };
+    }
+
+    // Each of these attributes renders the value on its own, only one can be used.
+    // `into` is allowed with `map` where it names the type the function returns
+    let renderers = [
+        ("display", field.attr.display),
+        ("debug", field.attr.debug),
+        ("date_format", field.attr.date_format.is_some()),
+        ("bool_labels", field.attr.bool_labels.is_some()),
+        ("precision", field.attr.precision.is_some()),
+        ("thousands", field.attr.thousands.is_some()),
+        ("map", field.attr.map.is_some()),
+        (
+            "into",
+            field.attr.into.is_some() && field.attr.map.is_none(),
+        ),
+    ];
+    if let [(first, _), (second, _), ..] = renderers
+        .iter()
+        .filter(|(_, used)| *used)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        abort! {field.ident, "`{}` can not be used with `{}`", first, second}
+    }
 
     let value = match (&field.attr.map, &field.attr.into) {
-        (Some(func), _) => {
+        _ if field.attr.date_format.is_some() => {
+            let date_format = &field.attr.date_format;
+            quote! {
+                #ident.format(#date_format).to_string()
+            }
+        }
+        _ if field.attr.bool_labels.is_some() => {
+            let (true_label, false_label) = field.attr.bool_labels.as_ref().unwrap();
+            quote! {
+                if *#ident { #true_label } else { #false_label }.to_string()
+            }
+        }
+        _ if field.attr.precision.is_some() => {
+            if !path_is_float(&field.typ) {
+                abort! {field.ident, "`precision` can only be used on a `f32` or `f64` field"}
+            }
+            let precision = &field.attr.precision;
+            quote! {
+                format!("{:.*}", #precision, #ident)
+            }
+        }
+        _ if field.attr.thousands.is_some() => {
+            if !path_is_number(&field.typ) {
+                abort! {field.ident, "`thousands` can only be used on an integer or a float field"}
+            }
+            let separator = &field.attr.thousands;
+            quote! {
+                descriptor::group_thousands(#ident, #separator)
+            }
+        }
+        _ if field.attr.display => {
+            quote! {
+                format!("{}", #ident)
+            }
+        }
+        _ if field.attr.debug => {
             quote! {
-                #func(#ident)
+                format!("{:?}", #ident)
             }
         }
+        (Some(func), _) => {
+            let args = &field.attr.args;
+            let value = if field.attr.method {
+                quote!(#ident.#func(#(#args),*))
+            } else {
+                quote!(#func(#ident #(, #args)*))
+            };
+            if field.attr.fallible {
+                // The error is shown in place of the value
+                quote! {
+                    match #value {
+                        Ok(value) => value,
+                        Err(err) => format!("Error: {}", err),
+                    }
+                }
+            } else {
+                value
+            }
+        }
+        _ if field.attr.method || field.attr.fallible || !field.attr.args.is_empty() => {
+            abort! {field.ident, "`method`, `args` and `fallible` require `map`"}
+        }
         (_, Some(into)) => {
             quote! {
                 Into::<#into>::into(#ident)
@@ -397,27 +1081,37 @@ fn field_getter(field: &StructField, method: TokenStream) -> TokenStream {
         }
     };
 
+    let value = match field.attr.show_sign {
+        Some(signed_zero) => quote! {
+            descriptor::show_sign(#value.clone(), #signed_zero)
+        },
+        None => value,
+    };
+
     if path_is_option(&field.typ) && field.attr.resolve_option {
         quote! {
-            if let Some(#ident) = &self.#ident {
+            if let Some(#ident) = #access {
                 #value.#method
             } else {
-                self.#ident.#method
+                // Rendered like any `None`, without requiring the inner type to be described
+                None::<String>.#method
             }
         }
     } else {
         quote! {
-            let #ident = &self.#ident;
+            let #ident = #access;
             #value.#method
         }
     }
 }
 
 // Generate decriptor Trait impl for Enum.
+// Unit variants are described by their name, data variants as a title followed by their fields
 fn generate_enum_decriptor(input: ItemEnum) -> proc_macro::TokenStream {
     let enum_name = &input.ident;
 
     let mut match_fields = quote! {};
+    let mut match_describe = quote! {};
 
     for variant in input.variants {
         let name = variant.ident;
@@ -429,9 +1123,87 @@ fn generate_enum_decriptor(input: ItemEnum) -> proc_macro::TokenStream {
             quote!(stringify!(#name))
         };
 
+        let all_fields = fields_of(&variant.fields, false);
+        let bindings = all_fields.iter().map(|field| {
+            let ident = &field.ident;
+            match (&field.member, field.attr.skip) {
+                (Member::Named(_), true) => quote!(#ident: _),
+                (Member::Unnamed(_), true) => quote!(_),
+                (_, false) => quote!(#ident),
+            }
+        });
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote!(#enum_name::#name { #(#bindings),* }),
+            Fields::Unnamed(_) => quote!(#enum_name::#name(#(#bindings),*)),
+            Fields::Unit => quote!(#enum_name::#name),
+        };
+        let fields = all_fields
+            .into_iter()
+            .filter(|x| !x.attr.skip)
+            .collect::<Vec<_>>();
+
+        if fields.is_empty() {
+            match_fields.extend(quote! {
+                #pattern => #value.to_string(),
+            });
+            match_describe.extend(quote! {
+                #pattern => ctx.write_value(writer, #value.to_string()),
+            });
+            continue;
+        }
+
+        let values = fields.iter().map(|field| {
+            let getter = field_getter(field, quote!(to_field("")));
+            match &field.member {
+                Member::Named(_) => {
+                    let field_name = &field.field_name;
+                    quote!(format!("{}={}", #field_name, { #getter }))
+                }
+                Member::Unnamed(_) => quote!({ #getter }),
+            }
+        });
         match_fields.extend(quote! {
-            #enum_name::#name => #value.to_string(),
-        })
+            #pattern => {
+                let values: Vec<String> = vec![#(#values),*];
+                format!("{}: {}", #value, values.join(", "))
+            },
+        });
+
+        let describe = match (&variant.fields, fields.as_slice()) {
+            (Fields::Unnamed(_), [field]) => field_getter(
+                field,
+                quote! {
+                    describe(writer, ctx.indent(#value.len() + 1, #value.len()))?;
+                },
+            ),
+            (_, fields) => {
+                let pad = pad_struct(fields);
+                let ctx = if fields.iter().any(|x| !x.attr.skip_description) {
+                    quote!(mut ctx)
+                } else {
+                    quote!(ctx)
+                };
+                let mut describe = quote! {
+                    let #ctx = ctx.indent(#value.len() + 1, #value.len());
+                    let pad = { #pad };
+                };
+                fields
+                    .iter()
+                    .filter(|x| !x.attr.skip_description)
+                    .enumerate()
+                    .map(|(i, x)| describe_field(x, i == 0, &quote!(pad)))
+                    .for_each(|value| describe.extend(value));
+                describe
+            }
+        };
+
+        match_describe.extend(quote! {
+            #pattern => {
+                ctx.write_title(writer, #value, true)?;
+                #describe
+                Ok(())
+            },
+        });
     }
 
     let to_field = quote! {
@@ -441,20 +1213,65 @@ fn generate_enum_decriptor(input: ItemEnum) -> proc_macro::TokenStream {
     };
 
     let describe = quote! {
-        ctx.write_value(writer, self.to_field(""))
+        match self {
+            #match_describe
+        }
     };
-    generate_trait(&input.ident, describe, to_field, None, None, None, None).into()
+    generate_trait(
+        &input.ident,
+        &input.generics,
+        TraitMethods {
+            describe,
+            to_field,
+            to_field_with_null: None,
+            pad: None,
+            default_headers: None,
+            headers: None,
+            header_name: None,
+            machine_key: None,
+            min_width: None,
+        },
+    )
+    .into()
+}
+
+// Add a `Describe` bound on every type parameter
+fn add_trait_bounds(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.push(parse_quote!(descriptor::Describe));
+        }
+    }
+    generics
 }
 
-fn generate_trait(
-    name: &Ident,
+// The body of each generated method, `None` keeps the default implementation of the trait
+struct TraitMethods {
     describe: TokenStream,
     to_field: TokenStream,
+    to_field_with_null: Option<TokenStream>,
     pad: Option<TokenStream>,
     default_headers: Option<TokenStream>,
     headers: Option<TokenStream>,
     header_name: Option<TokenStream>,
-) -> TokenStream {
+    machine_key: Option<TokenStream>,
+    min_width: Option<TokenStream>,
+}
+
+fn generate_trait(name: &Ident, generics: &Generics, methods: TraitMethods) -> TokenStream {
+    let TraitMethods {
+        describe,
+        to_field,
+        to_field_with_null,
+        pad,
+        default_headers,
+        headers,
+        header_name,
+        machine_key,
+        min_width,
+    } = methods;
+
     let default_headers = match &default_headers {
         None => quote! {},
         Some(headers) => quote! {
@@ -481,6 +1298,33 @@ fn generate_trait(
         },
     };
 
+    let machine_key = match &machine_key {
+        None => quote! {},
+        Some(machine_key) => quote! {
+            fn machine_key(header: &str) -> Option<String> {
+                #machine_key
+            }
+        },
+    };
+
+    let min_width = match &min_width {
+        None => quote! {},
+        Some(min_width) => quote! {
+            fn min_width(header: &str) -> usize {
+                #min_width
+            }
+        },
+    };
+
+    let to_field_with_null = match &to_field_with_null {
+        None => quote! {},
+        Some(to_field_with_null) => quote! {
+            fn to_field_with_null(&self, field_name: &str, null_placeholder: &str) -> String {
+                #to_field_with_null
+            }
+        },
+    };
+
     let pad = match &pad {
         None => quote! {},
         Some(pad) => quote! {
@@ -490,16 +1334,24 @@ fn generate_trait(
         },
     };
 
+    let generics = add_trait_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     quote! {
-        impl descriptor::Describe for #name {
+        impl #impl_generics descriptor::Describe for #name #ty_generics #where_clause {
             fn describe<W>(&self, writer: &mut W, ctx: descriptor::Context) -> std::io::Result<()>
             where
                 W: std::io::Write,
             {
+                if ctx.depth_exceeded() {
+                    return ctx.write_value(writer, "…".to_string());
+                }
                 #describe
             }
 
             #header_name
+            #machine_key
+            #min_width
             #headers
             #default_headers
             #pad
@@ -507,6 +1359,8 @@ fn generate_trait(
             fn to_field(&self, field_name: &str) -> String {
                 #to_field
             }
+
+            #to_field_with_null
         }
     }
 }
@@ -521,3 +1375,85 @@ fn path_is_option(ty: &Type) -> bool {
         _ => false,
     }
 }
+
+// `f32` or `f64`, optional or not
+fn path_is_float(ty: &Type) -> bool {
+    path_is_primitive(ty, &["f32", "f64"])
+}
+
+fn path_is_number(ty: &Type) -> bool {
+    const NUMBERS: [&str; 14] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+        "f32", "f64",
+    ];
+    path_is_primitive(ty, &NUMBERS)
+}
+
+// Whether the type, or the type inside an `Option`, is one of the given primitives
+fn path_is_primitive(ty: &Type, names: &[&str]) -> bool {
+    let segment = match ty {
+        Type::Path(TypePath { path, .. }) => path.segments.last(),
+        _ => None,
+    };
+    match segment {
+        Some(segment) if names.iter().any(|name| segment.ident == name) => true,
+        Some(segment) if segment.ident == "Option" => match &segment.arguments {
+            PathArguments::AngleBracketed(args) => {
+                matches!(args.args.first(), Some(GenericArgument::Type(inner)) if path_is_primitive(inner, names))
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// Types with an `is_empty` method
+fn path_is_collection(ty: &Type) -> bool {
+    const COLLECTIONS: [&str; 7] = [
+        "String", "Vec", "VecDeque", "HashMap", "HashSet", "BTreeMap", "BTreeSet",
+    ];
+    match ty {
+        Type::Path(TypePath { path, .. }) => {
+            matches!(path.segments.last(), Some(segment) if COLLECTIONS.iter().any(|name| segment.ident == name))
+        }
+        _ => false,
+    }
+}
+
+// Types described as a bulleted list, optional or behind a reference
+fn path_is_list(ty: &Type) -> bool {
+    const LISTS: [&str; 3] = ["Vec", "HashSet", "BTreeSet"];
+    match ty {
+        Type::Array(_) | Type::Slice(_) => true,
+        Type::Reference(reference) => path_is_list(&reference.elem),
+        Type::Path(TypePath { path, .. }) => match path.segments.last() {
+            Some(segment) if segment.ident == "Option" => match &segment.arguments {
+                PathArguments::AngleBracketed(args) => {
+                    matches!(args.args.first(), Some(GenericArgument::Type(inner)) if path_is_list(inner))
+                }
+                _ => false,
+            },
+            Some(segment) => LISTS.iter().any(|name| segment.ident == name),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+fn path_is_vec(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { path, .. }) => {
+            matches!(path.segments.last(), Some(segment) if segment.ident == "Vec")
+        }
+        _ => false,
+    }
+}
+
+fn path_is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { path, .. }) => {
+            matches!(path.segments.last(), Some(segment) if segment.ident == "PhantomData")
+        }
+        _ => false,
+    }
+}
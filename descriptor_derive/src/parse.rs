@@ -1,13 +1,19 @@
+use proc_macro2::TokenStream;
 use proc_macro_error::{abort, ResultExt};
-use syn::parse::{Parse, ParseStream};
+use syn::parse::{Parse, ParseStream, Parser};
 use syn::punctuated::Punctuated;
-use syn::{self, Attribute, Expr, Ident, LitStr, Token};
+use syn::{
+    self, parenthesized, token, Attribute, Expr, ExprLit, Ident, Lit, LitStr, Meta, MetaNameValue,
+    Token,
+};
 
 pub struct DescriptorAttr {
     ident: Ident,
     attribute: String,
     expr: Option<Expr>,
     value: Option<String>,
+    // Content of `name(...)` attributes
+    list: Option<TokenStream>,
 }
 
 #[derive(Clone)]
@@ -16,6 +22,18 @@ pub struct DescriptorStructAttr {
     pub headers: Option<Expr>,
     pub map: Option<Expr>,
     pub extra_fields: Option<Expr>,
+    pub transparent: bool,
+    // Computed fields of `duration_between(name = "...", start = "...", end = "...")`
+    pub duration_between: Vec<(String, String, String)>,
+}
+
+// A step of the `pipe(...)` attribute
+#[derive(Clone)]
+pub enum Transform {
+    Uppercase,
+    Lowercase,
+    Prefix(String),
+    Suffix(String),
 }
 
 #[derive(Clone)]
@@ -24,12 +42,48 @@ pub struct DescriptorFieldAttr {
     pub skip_description: bool,
     pub skip: bool,
     pub output_table: bool,
+    pub output_table_headers: Option<Expr>,
     pub resolve_option: bool,
     pub into: Option<Expr>,
     pub map: Option<Expr>,
     pub rename_description: Option<String>,
     pub rename_header: Option<String>,
+    pub rename_header_fn: Option<Expr>,
+    pub rename: Option<String>,
+    // First line of the doc comment with `doc`, used as label when the field is not renamed
+    pub doc: Option<String>,
     pub flatten: bool,
+    pub prefix: Option<String>,
+    pub flatten_option: bool,
+    pub json_inline: bool,
+    // Only set on the computed fields of the struct `duration_between` attribute
+    pub duration_between: Option<(String, String)>,
+    pub date_format: Option<String>,
+    pub empty: Option<String>,
+    pub default: Option<String>,
+    pub pipe: Vec<Transform>,
+    pub color: Option<Expr>,
+    pub max_width: Option<Expr>,
+    pub min_width: Option<usize>,
+    pub spread: Option<usize>,
+    pub wrap: Option<Expr>,
+    pub key: Option<String>,
+    pub order: Option<usize>,
+    pub skip_if_none: bool,
+    pub skip_if_empty: bool,
+    pub display: bool,
+    pub debug: bool,
+    pub join: Option<String>,
+    pub numbered: bool,
+    pub method: bool,
+    pub fallible: bool,
+    pub args: Vec<Expr>,
+    pub bool_labels: Option<(String, String)>,
+    pub precision: Option<usize>,
+    // Separator of the digit groups
+    pub thousands: Option<String>,
+    // Whether zero is signed too
+    pub show_sign: Option<bool>,
 }
 
 impl Parse for DescriptorAttr {
@@ -48,6 +102,7 @@ impl Parse for DescriptorAttr {
                     attribute: name_str,
                     expr: None,
                     value: Some(lit.value()),
+                    list: None,
                 })
             } else {
                 match input.parse::<Expr>() {
@@ -56,6 +111,7 @@ impl Parse for DescriptorAttr {
                         attribute: name_str,
                         expr: Some(expr),
                         value: None,
+                        list: None,
                     }),
                     Err(_) => abort! {
                         assign_token,
@@ -63,6 +119,17 @@ impl Parse for DescriptorAttr {
                     },
                 }
             }
+        } else if input.peek(token::Paren) {
+            // `name(...)` attributes, the content is parsed by each attribute.
+            let content;
+            parenthesized!(content in input);
+            Ok(Self {
+                ident,
+                attribute: name_str,
+                expr: None,
+                value: None,
+                list: Some(content.parse()?),
+            })
         } else {
             // Attributes represented with a sole identifier.
             Ok(Self {
@@ -70,6 +137,7 @@ impl Parse for DescriptorAttr {
                 attribute: name_str,
                 expr: None,
                 value: None,
+                list: None,
             })
         }
     }
@@ -86,12 +154,23 @@ pub fn parse_attributes(all_attrs: &[Attribute]) -> Vec<DescriptorAttr> {
         .collect()
 }
 
+// Parse the content of a `name(...)` attribute as a list of attributes
+fn parse_list(list: TokenStream) -> Vec<DescriptorAttr> {
+    Punctuated::<DescriptorAttr, Token![,]>::parse_terminated
+        .parse2(list)
+        .unwrap_or_abort()
+        .into_iter()
+        .collect()
+}
+
 pub fn extract_struct_attributes(all_attrs: &[Attribute]) -> DescriptorStructAttr {
     let mut struct_attr = DescriptorStructAttr {
         into: None,
         headers: None,
         map: None,
         extra_fields: None,
+        transparent: false,
+        duration_between: vec![],
     };
 
     for attr in parse_attributes(all_attrs) {
@@ -100,7 +179,17 @@ pub fn extract_struct_attributes(all_attrs: &[Attribute]) -> DescriptorStructAtt
             attribute,
             expr,
             value,
+            list,
         } = attr;
+        if let Some(list) = list {
+            match attribute.as_str() {
+                "duration_between" => struct_attr
+                    .duration_between
+                    .push(extract_duration_between(&ident, list)),
+                _ => abort! {ident,"unknown parameter"},
+            }
+            continue;
+        }
         match (attribute.as_str(), expr, value, ident) {
             ("into", Some(expr), ..) => struct_attr.into = Some(expr),
             ("into", _, _, ident) => {
@@ -118,6 +207,10 @@ pub fn extract_struct_attributes(all_attrs: &[Attribute]) -> DescriptorStructAtt
             ("default_headers", _, _, ident) => {
                 abort! {ident,"expected `string literal` or `expression` after `=`"}
             }
+            ("transparent", None, None, ..) => struct_attr.transparent = true,
+            ("transparent", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
             (.., ident) => abort! {ident,"unknown parameter"},
         }
     }
@@ -131,12 +224,44 @@ pub fn extract_field_attributes(all_attrs: &[Attribute]) -> DescriptorFieldAttr
         skip_description: false,
         skip: false,
         output_table: false,
+        output_table_headers: None,
         flatten: false,
+        prefix: None,
+        flatten_option: true,
+        json_inline: false,
+        duration_between: None,
+        date_format: None,
+        empty: None,
+        default: None,
+        pipe: vec![],
+        color: None,
+        max_width: None,
+        min_width: None,
+        spread: None,
+        wrap: None,
+        key: None,
+        order: None,
+        skip_if_none: false,
+        skip_if_empty: false,
+        display: false,
+        debug: false,
+        join: None,
+        numbered: false,
+        method: false,
+        fallible: false,
+        args: Vec::new(),
+        bool_labels: None,
+        precision: None,
+        thousands: None,
+        show_sign: None,
         resolve_option: false,
         rename_header: None,
+        rename_header_fn: None,
+        rename: None,
         rename_description: None,
         map: None,
         into: None,
+        doc: None,
     };
 
     for attr in parse_attributes(all_attrs) {
@@ -145,8 +270,26 @@ pub fn extract_field_attributes(all_attrs: &[Attribute]) -> DescriptorFieldAttr
             attribute,
             expr,
             value,
+            list,
         } = attr;
+        if let Some(list) = list {
+            match attribute.as_str() {
+                "show_sign" => field_attribute.show_sign = Some(extract_show_sign(list)),
+                "pipe" => field_attribute.pipe = extract_pipe(list),
+                "args" => field_attribute.args = extract_args(list),
+                _ => abort! {ident,"unknown parameter"},
+            }
+            continue;
+        }
         match (attribute.as_str(), expr, value, ident) {
+            ("method", None, None, ..) => field_attribute.method = true,
+            ("method", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
+            ("fallible", None, None, ..) => field_attribute.fallible = true,
+            ("fallible", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
             ("skip_header", None, None, ..) => field_attribute.skip_header = true,
             ("skip_header", _, _, ident) => {
                 abort! {ident,"not expected `string literal` or `expression` after `=`"}
@@ -159,9 +302,40 @@ pub fn extract_field_attributes(all_attrs: &[Attribute]) -> DescriptorFieldAttr
             ("skip", _, _, ident) => {
                 abort! {ident,"not expected `string literal` or `expression` after `=`"}
             }
+            ("doc", None, None, ident) => match extract_doc(all_attrs) {
+                Some(doc) => field_attribute.doc = Some(doc),
+                None => abort! {ident,"expected a `///` doc comment on the field"},
+            },
+            ("doc", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
+            ("skip_if_none", None, None, ..) => field_attribute.skip_if_none = true,
+            ("skip_if_none", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
+            ("skip_if_empty", None, None, ..) => field_attribute.skip_if_empty = true,
+            ("skip_if_empty", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
+            ("display", None, None, ..) => field_attribute.display = true,
+            ("display", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
+            ("debug", None, None, ..) => field_attribute.debug = true,
+            ("debug", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
+            ("numbered", None, None, ..) => field_attribute.numbered = true,
+            ("numbered", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
             ("output_table", None, None, ..) => field_attribute.output_table = true,
+            ("output_table", Some(expr), None, ..) => {
+                field_attribute.output_table = true;
+                field_attribute.output_table_headers = Some(expr)
+            }
             ("output_table", _, _, ident) => {
-                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+                abort! {ident,"expected `expression` after `=`"}
             }
             ("map", Some(expr), None, ..) => field_attribute.map = Some(expr),
             ("map", _, _, ident) => {
@@ -171,6 +345,26 @@ pub fn extract_field_attributes(all_attrs: &[Attribute]) -> DescriptorFieldAttr
             ("flatten", _, _, ident) => {
                 abort! {ident,"not expected `string literal` or `expression` after `=`"}
             }
+            ("prefix", None, Some(val), ..) => field_attribute.prefix = Some(val),
+            ("prefix", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            ("flatten_option", Some(expr), None, ident) => {
+                field_attribute.flatten_option = match expr {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Bool(lit),
+                        ..
+                    }) => lit.value,
+                    _ => abort! {ident,"expected `true` or `false` after `=`"},
+                }
+            }
+            ("flatten_option", _, _, ident) => {
+                abort! {ident,"expected `true` or `false` after `=`"}
+            }
+            ("json_inline", None, None, ..) => field_attribute.json_inline = true,
+            ("json_inline", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
             ("resolve_option", None, None, ..) => field_attribute.resolve_option = true,
             ("resolve_option", _, _, ident) => {
                 abort! {ident,"not expected `string literal` or `expression` after `=`"}
@@ -181,10 +375,122 @@ pub fn extract_field_attributes(all_attrs: &[Attribute]) -> DescriptorFieldAttr
             ("rename_description", _, _, ident) => {
                 abort! {ident,"expected `string literal` or `expression` after `=`"}
             }
+            ("rename_header_fn", Some(expr), None, ..) => {
+                field_attribute.rename_header_fn = Some(expr)
+            }
+            ("rename_header_fn", _, _, ident) => {
+                abort! {ident,"expected `expression` after `=`"}
+            }
             ("rename_header", None, Some(val), ..) => field_attribute.rename_header = Some(val),
             ("rename_header", _, _, ident) => {
                 abort! {ident,"expected `string literal` or `expression` after `=`"}
             }
+            ("rename", None, Some(val), ..) => field_attribute.rename = Some(val),
+            ("rename", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            ("date_format", None, Some(val), ..) => field_attribute.date_format = Some(val),
+            ("date_format", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            ("color", Some(expr), None, ..) => field_attribute.color = Some(expr),
+            ("color", _, _, ident) => {
+                abort! {ident,"expected `expression` after `=`"}
+            }
+            ("max_width", Some(expr), None, ..) => field_attribute.max_width = Some(expr),
+            ("max_width", _, _, ident) => {
+                abort! {ident,"expected `expression` after `=`"}
+            }
+            ("wrap", Some(expr), None, ..) => field_attribute.wrap = Some(expr),
+            ("wrap", _, _, ident) => {
+                abort! {ident,"expected `expression` after `=`"}
+            }
+            (
+                "min_width",
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                })),
+                None,
+                ..,
+            ) => field_attribute.min_width = Some(lit.base10_parse().unwrap_or_abort()),
+            ("min_width", _, _, ident) => {
+                abort! {ident,"expected `integer` after `=`"}
+            }
+            (
+                "spread",
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                })),
+                None,
+                ..,
+            ) => field_attribute.spread = Some(lit.base10_parse().unwrap_or_abort()),
+            ("spread", _, _, ident) => {
+                abort! {ident,"expected `integer` after `=`"}
+            }
+            (
+                "order",
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                })),
+                None,
+                ..,
+            ) => field_attribute.order = Some(lit.base10_parse().unwrap_or_abort()),
+            ("order", _, _, ident) => {
+                abort! {ident,"expected `integer` after `=`"}
+            }
+            ("join", None, Some(val), ..) => field_attribute.join = Some(val),
+            ("join", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            ("bool_labels", None, Some(val), ident) => match val.split_once('/') {
+                Some((true_label, false_label)) => {
+                    field_attribute.bool_labels =
+                        Some((true_label.to_string(), false_label.to_string()))
+                }
+                None => abort! {ident,"expected `\"true/false\"` labels separated by `/`"},
+            },
+            ("bool_labels", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            (
+                "precision",
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                })),
+                None,
+                ..,
+            ) => field_attribute.precision = Some(lit.base10_parse().unwrap_or_abort()),
+            ("precision", _, _, ident) => {
+                abort! {ident,"expected `integer` after `=`"}
+            }
+            ("thousands", None, None, ..) => {
+                field_attribute
+                    .thousands
+                    .get_or_insert_with(|| ",".to_string());
+            }
+            ("thousands", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
+            ("thousands_sep", None, Some(val), ..) => field_attribute.thousands = Some(val),
+            ("thousands_sep", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            ("key", None, Some(val), ..) => field_attribute.key = Some(val),
+            ("key", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            ("default", None, Some(val), ..) => field_attribute.default = Some(val),
+            ("default", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            ("empty", None, Some(val), ..) => field_attribute.empty = Some(val),
+            ("empty", _, _, ident) => {
+                abort! {ident,"expected `string literal` after `=`"}
+            }
+            ("show_sign", None, None, ..) => field_attribute.show_sign = Some(true),
+            ("show_sign", _, _, ident) => {
+                abort! {ident,"not expected `string literal` or `expression` after `=`"}
+            }
             ("into", Some(expr), ..) => field_attribute.into = Some(expr),
             ("into", _, _, ident) => {
                 abort! {ident,"expected `string literal` or `expression` after `=`"}
@@ -195,3 +501,93 @@ pub fn extract_field_attributes(all_attrs: &[Attribute]) -> DescriptorFieldAttr
 
     field_attribute
 }
+
+// First non blank line of the `///` doc comment
+fn extract_doc(all_attrs: &[Attribute]) -> Option<String> {
+    all_attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            })) => Some(lit.value().trim().to_string()),
+            _ => None,
+        })
+        .find(|line| !line.is_empty())
+}
+
+// Parse `duration_between(name = "elapsed", start = "field", end = "field")`
+fn extract_duration_between(ident: &Ident, list: TokenStream) -> (String, String, String) {
+    let mut name = None;
+    let mut start = None;
+    let mut end = None;
+
+    for attr in parse_list(list) {
+        match (attr.attribute.as_str(), attr.value) {
+            ("name", Some(val)) => name = Some(val),
+            ("start", Some(val)) => start = Some(val),
+            ("end", Some(val)) => end = Some(val),
+            ("name", None) | ("start", None) | ("end", None) => {
+                abort! {attr.ident,"expected `string literal` after `=`"}
+            }
+            _ => abort! {attr.ident,"unknown parameter"},
+        }
+    }
+
+    match (name, start, end) {
+        (Some(name), Some(start), Some(end)) => (name, start, end),
+        _ => abort! {ident,"expected `name`, `start` and `end`"},
+    }
+}
+
+// Parse `show_sign(signed_zero = false)`
+fn extract_show_sign(list: TokenStream) -> bool {
+    let mut signed_zero = true;
+
+    for attr in parse_list(list) {
+        match (attr.attribute.as_str(), attr.expr) {
+            (
+                "signed_zero",
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Bool(lit),
+                    ..
+                })),
+            ) => signed_zero = lit.value,
+            ("signed_zero", _) => abort! {attr.ident,"expected `true` or `false` after `=`"},
+            _ => abort! {attr.ident,"unknown parameter"},
+        }
+    }
+
+    signed_zero
+}
+
+// Parse `pipe(uppercase, prefix = "[", suffix = "]")`, keeping the order
+fn extract_pipe(list: TokenStream) -> Vec<Transform> {
+    parse_list(list)
+        .into_iter()
+        .map(
+            |attr| match (attr.attribute.as_str(), attr.expr, attr.value) {
+                ("uppercase", None, None) => Transform::Uppercase,
+                ("lowercase", None, None) => Transform::Lowercase,
+                ("prefix", None, Some(val)) => Transform::Prefix(val),
+                ("suffix", None, Some(val)) => Transform::Suffix(val),
+                ("uppercase", ..) | ("lowercase", ..) => {
+                    abort! {attr.ident,"not expected `string literal` or `expression` after `=`"}
+                }
+                ("prefix", ..) | ("suffix", ..) => {
+                    abort! {attr.ident,"expected `string literal` after `=`"}
+                }
+                _ => abort! {attr.ident,"unknown parameter"},
+            },
+        )
+        .collect()
+}
+
+// Parse `args(80, "x")`, any expression is accepted
+fn extract_args(list: TokenStream) -> Vec<Expr> {
+    Punctuated::<Expr, Token![,]>::parse_terminated
+        .parse2(list)
+        .unwrap_or_abort()
+        .into_iter()
+        .collect()
+}
@@ -0,0 +1,65 @@
+//! Functions ready to be used with the `map` attribute
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct File {
+//!     #[descriptor(map = descriptor::helpers::humanize_bytes)]
+//!     size: u64,
+//! }
+//!
+//! let description = object_describe_to_string(&File { size: 1536 }).unwrap();
+//! assert_eq!("\nSize: 1.5 KiB\n", description);
+//! ```
+
+/// Render a number of bytes with binary units: `512 B`, `1.5 KiB`, `2.0 GiB`
+pub fn humanize_bytes(bytes: &u64) -> String {
+    const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    if *bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = *bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Render a number of seconds with its two most significant units, rounding the smallest one: `2h 3m`
+///
+/// Zero components are dropped, `7200` is rendered as `2h`.
+pub fn humanize_duration(secs: &u64) -> String {
+    const UNITS: [(u64, &str); 4] = [(86400, "d"), (3600, "h"), (60, "m"), (1, "s")];
+
+    let secs = *secs;
+    let idx = UNITS
+        .iter()
+        .position(|(size, _)| secs >= *size)
+        .unwrap_or(UNITS.len() - 1);
+    let (size, unit) = UNITS[idx];
+    if size == 1 {
+        return format!("{}{}", secs, unit);
+    }
+
+    let (small_size, small_unit) = UNITS[idx + 1];
+    let total = secs.saturating_add(small_size / 2) / small_size;
+    // Rounding up may reach the next unit, `86399` is rendered as `1d`
+    if idx > 0 && total * small_size >= UNITS[idx - 1].0 {
+        return humanize_duration(&(total * small_size));
+    }
+    let ratio = size / small_size;
+    match (total / ratio, total % ratio) {
+        (big, 0) => format!("{}{}", big, unit),
+        (big, small) => format!("{}{} {}{}", big, unit, small, small_unit),
+    }
+}
+
+/// Cap a string to `max` columns without splitting graphemes, ending with `…`: `#[descriptor(map = descriptor::helpers::truncate(40))]`
+pub fn truncate(max: usize) -> impl Fn(&String) -> String {
+    move |value| crate::truncate(value, max)
+}
@@ -144,6 +144,36 @@
 //! "#,  description);
 //! ```
 //!
+//! ### `#[descriptor(duration_between(name = "elapsed", start = "started_at", end = "ended_at"))]`
+//!
+//! Add a field with the time elapsed between two `Timestamp` fields, `~` when one is missing.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//! use std::time::{Duration, SystemTime};
+//!
+//! #[derive(Descriptor)]
+//! #[descriptor(duration_between(name = "elapsed", start = "started_at", end = "ended_at"))]
+//! struct Job {
+//!     name: String,
+//!     #[descriptor(skip)]
+//!     started_at: SystemTime,
+//!     #[descriptor(skip)]
+//!     ended_at: Option<SystemTime>,
+//! }
+//!
+//! let job = Job {
+//!     name: "build".to_string(),
+//!     started_at: SystemTime::UNIX_EPOCH,
+//!     ended_at: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(90)),
+//! };
+//! let description = object_describe_to_string(&job).unwrap();
+//! assert_eq!(r#"
+//! Name:    build
+//! Elapsed: 1m 30s
+//! "#,  description);
+//! ```
+//!
 //! ### `#[descriptor(default_headers = [""])]`
 //!
 //! Overrides default headers when using the table output.
@@ -169,11 +199,38 @@
 //! "#,  format!("\n{}", description));
 //! ```
 //!
+//! ### `#[descriptor(transparent)]`
+//!
+//! Describe a single field struct exactly as its field, e.g. a `struct UserId(String)` newtype.
+//!
+//! ```
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! #[descriptor(transparent)]
+//! struct UserId(String);
+//!
+//! #[derive(Descriptor)]
+//! struct User {
+//!     id: UserId,
+//!     name: String,
+//! }
+//!
+//! let users = vec![User{id: UserId("u-1".to_string()), name: "Adrien".to_string()}];
+//! let table = table_describe_to_string(&users).unwrap();
+//! assert_eq!(r#"
+//! ID  NAME
+//! u-1 Adrien
+//! "#,  format!("\n{}", table));
+//! ```
+//!
 //! ## Field attributes
 
 //! #### `#[descriptor(flatten)]`
 //! Flatten a struct into another.
 //!
+//! An `Option` struct is flattened when set, and rendered as `~` otherwise.
+//!
 //! ```
 //! use descriptor::{Descriptor, object_describe_to_string};
 //!
@@ -209,6 +266,109 @@
 //! "#,  description);
 //! ```
 //!
+//! ### `#[descriptor(flatten_option = false)]`
+//! Render an optional nested struct in a single table column instead of one column per inner field.
+//!
+//! ```
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct User {
+//!     name: String,
+//!     #[descriptor(flatten_option = false)]
+//!     address: Option<Address>,
+//! }
+//!
+//! #[derive(Descriptor)]
+//! struct Address {
+//!     town: String,
+//! }
+//!
+//! let users = vec![
+//!     User{name: "Adrien".to_string(), address: Some(Address{town: "NY".to_string()})},
+//!     User{name: "Corentin".to_string(), address: None},
+//! ];
+//! let table = table_describe_to_string(&users).unwrap();
+//!
+//! assert_eq!(r#"
+//! NAME     ADDRESS
+//! Adrien   town=NY
+//! Corentin ~
+//! "#,  format!("\n{}", table));
+//! ```
+//!
+//! ### `#[descriptor(flatten, prefix = "home_")]`
+//!
+//! Prefix the headers and titles of a flattened struct, so two structs of the same type
+//! can be flattened side by side: `home_street` / `Home Street`.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Customer {
+//!     name: String,
+//!     #[descriptor(flatten, prefix = "home_")]
+//!     home: Address,
+//!     #[descriptor(flatten, prefix = "work_")]
+//!     work: Address,
+//! }
+//!
+//! #[derive(Descriptor)]
+//! struct Address {
+//!     town: String,
+//! }
+//!
+//! let customers = vec![Customer{
+//!     name: "Adrien".to_string(),
+//!     home: Address{town: "NY".to_string()},
+//!     work: Address{town: "Boston".to_string()},
+//! }];
+//! let description = object_describe_to_string(&customers[0]).unwrap();
+//! assert_eq!(r#"
+//! Name:      Adrien
+//! Home Town: NY
+//! Work Town: Boston
+//! "#,  description);
+//!
+//! let table = table_describe_to_string(&customers).unwrap();
+//! assert_eq!(r#"
+//! NAME   HOME_TOWN WORK_TOWN
+//! Adrien NY        Boston
+//! "#,  format!("\n{}", table));
+//! ```
+//!
+//! ### `#[descriptor(json_inline)]`
+//!
+//! Render the field as compact JSON in tables, the description is not affected.
+//! Requires the `serde` feature and a field implementing `serde::Serialize`.
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct User {
+//!     name: String,
+//!     #[descriptor(json_inline)]
+//!     cars: Vec<Car>,
+//! }
+//!
+//! #[derive(Descriptor, serde::Serialize)]
+//! struct Car {
+//!     brand: String,
+//! }
+//!
+//! let users = vec![User{name: "Adrien".to_string(), cars: vec![Car{brand: "Audi".to_string()}]}];
+//! let table = table_describe_to_string(&users).unwrap();
+//! assert_eq!(r#"
+//! NAME   CARS
+//! Adrien [{"brand":"Audi"}]
+//! "#,  format!("\n{}", table));
+//! # }
+//! ```
+//!
 //! ### `#[descriptor(map = func)]`
 //! Takes a transformation function as parameter, called before generating the field.
 //!
@@ -264,6 +424,77 @@
 //! Age:  32 years
 //! "#,  description);
 //! ```
+//! ### `#[descriptor(map = render, method, args(80))]`
+//!
+//! With `method`, the function is called as a method of the field: `field.render(80)`.
+//! Without it, `args` are passed after the field: `render(&field, 80)`.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Progress {
+//!     done: u32,
+//!     total: u32,
+//! }
+//!
+//! impl Progress {
+//!     fn render(&self, width: usize) -> String {
+//!         let done = width * self.done as usize / self.total as usize;
+//!         format!("[{}{}]", "#".repeat(done), " ".repeat(width - done))
+//!     }
+//! }
+//!
+//! fn percent(progress: &Progress, precision: usize) -> String {
+//!     format!("{:.*}%", precision, 100.0 * progress.done as f64 / progress.total as f64)
+//! }
+//!
+//! #[derive(Descriptor)]
+//! struct Job {
+//!     #[descriptor(map = render, method, args(8))]
+//!     bar: Progress,
+//!     #[descriptor(map = percent, args(1))]
+//!     progress: Progress,
+//! }
+//!
+//! let job = Job{
+//!     bar: Progress{done: 1, total: 4},
+//!     progress: Progress{done: 1, total: 3},
+//! };
+//! let description = object_describe_to_string(&job).unwrap();
+//! assert_eq!(r#"
+//! Bar:      [##      ]
+//! Progress: 33.3%
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(map = try_fmt, fallible)]`
+//!
+//! The function returns a `Result<String, E: Display>`, an error is shown as `Error: …`.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! fn parse_port(port: &str) -> Result<String, std::num::ParseIntError> {
+//!     port.parse::<u16>().map(|port| format!(":{}", port))
+//! }
+//!
+//! #[derive(Descriptor)]
+//! struct Config {
+//!     #[descriptor(map = parse_port, fallible)]
+//!     port: String,
+//!     #[descriptor(map = parse_port, fallible)]
+//!     admin_port: String,
+//! }
+//!
+//! let config = Config{port: "8080".to_string(), admin_port: "80a".to_string()};
+//! let description = object_describe_to_string(&config).unwrap();
+//! assert_eq!(r#"
+//! Port:       :8080
+//! Admin Port: Error: invalid digit found in string
+//! "#,  description);
+//! ```
+//!
 //! ### `#[descriptor(into)]`
 //!
 //! Act like `into` parameter in struct level,
@@ -342,6 +573,9 @@
 //!   Mercedes   2
 //! "#,  description);
 //! ```
+//!
+//! Use `#[descriptor(output_table = ["name"])]` to restrict the columns of the table.
+//!
 //! ### `#[descriptor(skip)]`
 //!
 //! - `#[descriptor(skip)]`: Skip this field from description and default headers in table
@@ -414,138 +648,998 @@
 //! "#,  format!("\n{}", table));
 //! ```
 //!
-//! ## Enum parameters
-//! ### `#[descriptor(rename_description = "Renamed")]`
+//! ### `#[descriptor(rename_header_fn = translate)]`
 //!
-//! Rename the value for enums.
+//! Compute the table header at runtime, `translate` is called with the field name.
 //!
 //! ```
-//! use descriptor::{object_describe_to_string, Descriptor};
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! fn translate(field: &str) -> String {
+//!     match field {
+//!         "brand" => "MARQUE".to_string(),
+//!         field => field.to_uppercase(),
+//!     }
+//! }
+//!
 //! #[derive(Descriptor)]
-//! struct User {
-//!     name: String,
-//!     role: Role,
+//! struct Car {
+//!     #[descriptor(rename_header_fn = translate)]
+//!     brand: String,
+//!     #[descriptor(rename_header_fn = translate)]
+//!     seat: i16,
 //! }
+//!
+//! let cars = vec![Car{brand: "Audi".to_string(), seat: 4}];
+//! let table = table_describe_to_string(&cars).unwrap();
+//! assert_eq!(r#"
+//! MARQUE SEAT
+//! Audi   4
+//! "#,  format!("\n{}", table));
+//! ```
+//!
+//! ### `#[descriptor(rename = "Label")]`
+//!
+//! Rename the title of the field in the description, table headers are not affected.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
 //! #[derive(Descriptor)]
-//! enum Role {
-//!     Admin,
-//!     #[descriptor(rename_description = "User role")]
-//!     User,
+//! struct Server {
+//!     name: String,
+//!     #[descriptor(rename = "IP Address")]
+//!     ip: String,
 //! }
 //!
-//! let description = object_describe_to_string(&User {
-//!    name: "Adrien".to_string(),
-//!    role: Role::User,
+//! let description = object_describe_to_string(&Server {
+//!     name: "web".to_string(),
+//!     ip: "10.0.0.1".to_string(),
 //! }).unwrap();
 //! assert_eq!(r#"
-//! Name: Adrien
-//! Role: User role
+//! Name:       web
+//! IP Address: 10.0.0.1
 //! "#, description);
 //! ```
 //!
+//! ### `#[descriptor(doc)]`
 //!
-use std::collections::HashMap;
-use std::io;
-
-use convert_case::{Case, Casing};
-#[doc(hidden)]
-pub use descriptor_derive::{self, *};
-
-#[derive(Clone, Default)]
-pub struct Context {
-    pub offset: usize,
-    pub pad: usize,
-    pub upper_pad: usize,
-    pub is_array: bool,
-    pub title_size: usize,
-}
-
-impl Context {
-    pub fn pad(&self, upper_pad: usize) -> Self {
-        Self {
-            offset: self.offset,
-            upper_pad: self.upper_pad.max(upper_pad),
-            pad: 0,
-            title_size: 0,
-            is_array: false,
-        }
-    }
-
-    pub fn indent(&self, pad: usize, title_size: usize) -> Self {
-        Self {
-            offset: self.offset + 2,
-            pad: pad.max(self.upper_pad),
-            upper_pad: 0,
-            title_size,
-            is_array: false,
-        }
-    }
-
-    pub fn array(&self) -> Self {
-        Self {
-            offset: self.offset,
-            pad: self.pad,
-            title_size: 0,
-            upper_pad: 0,
-            is_array: true,
-        }
-    }
-
-    pub fn indent_and_table(&self) -> Self {
-        Self {
-            offset: self.offset + 2,
-            pad: 0,
-            upper_pad: 0,
-            title_size: 0,
-            is_array: true,
-        }
-    }
-
-    pub fn describe_table<T, W>(&self, data: &[T], writer: &mut W) -> io::Result<()>
-    where
-        T: Describe,
-        W: io::Write,
-    {
-        writeln!(writer)?;
-        Describer::describe_list_internal(data, &[], writer, self.indent_and_table())
-    }
-
-    pub fn write_title<W>(&self, writer: &mut W, field: &str, first_field: bool) -> io::Result<()>
-    where
-        W: io::Write,
-    {
-        writeln!(writer)?;
-        let offset = if first_field && self.is_array {
-            write!(writer, "{:<offset$}- ", "", offset = self.offset - 2)?;
-            0
-        } else {
-            self.offset
-        };
-
-        write!(
-            writer,
-            "{:<offset$}{}",
-            "",
-            format!("{}:", field),
-            offset = offset
-        )
-    }
-
-    pub fn write_value<W>(&self, writer: &mut W, field: String) -> io::Result<()>
-    where
-        W: io::Write,
-    {
-        if self.is_array {
-            writeln!(writer)?;
-            write!(
-                writer,
-                "{:<offset$}- {}",
-                "",
-                field,
-                offset = self.offset - 2
-            )
-        } else {
-            write!(
+//! Use the first line of the field `///` doc comment as its title and table header,
+//! `rename` and `rename_header` still take precedence.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Disk {
+//!     /// Mount point
+//!     #[descriptor(doc)]
+//!     path: String,
+//!     /// Ignored without `doc`
+//!     size: u64,
+//! }
+//!
+//! let description = object_describe_to_string(&Disk{path: "/".to_string(), size: 512}).unwrap();
+//! assert_eq!(r#"
+//! Mount point: /
+//! Size:        512
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(date_format = "%Y-%m-%d")]`
+//!
+//! Format a `chrono` date with the given format string, requires the `chrono` feature.
+//!
+//! Can be used with `resolve_option` for optional dates.
+//!
+//! ```
+//! # #[cfg(feature = "chrono")]
+//! # {
+//! use chrono::{DateTime, TimeZone, Utc};
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Event {
+//!     #[descriptor(date_format = "%Y-%m-%d")]
+//!     day: DateTime<Utc>,
+//!     #[descriptor(date_format = "%Y-%m-%d", resolve_option)]
+//!     deleted_at: Option<DateTime<Utc>>,
+//! }
+//!
+//! let event = Event{
+//!     day: Utc.with_ymd_and_hms(2021, 9, 15, 10, 30, 0).unwrap(),
+//!     deleted_at: None,
+//! };
+//! let description = object_describe_to_string(&event).unwrap();
+//! assert_eq!(r#"
+//! Day:        2021-09-15
+//! Deleted At: ~
+//! "#,  description);
+//! # }
+//! ```
+//!
+//! ### `#[descriptor(empty = "no cars")]`
+//!
+//! Message rendered in place of an empty collection.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Garage {
+//!     name: String,
+//!     #[descriptor(empty = "no cars")]
+//!     cars: Vec<String>,
+//! }
+//!
+//! let garage = Garage{name: "Central".to_string(), cars: vec![]};
+//! let description = object_describe_to_string(&garage).unwrap();
+//! assert_eq!(r#"
+//! Name: Central
+//! Cars: no cars
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(default = "unknown")]`
+//!
+//! Rendered in place of `~` when the `Option` is `None` or the `String`/collection is empty.
+//! Can not be used with `empty`, which only covers collections.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Server {
+//!     #[descriptor(default = "unknown")]
+//!     region: Option<String>,
+//!     zone: Option<String>,
+//! }
+//!
+//! let server = Server{region: None, zone: None};
+//! let description = object_describe_to_string(&server).unwrap();
+//! assert_eq!(r#"
+//! Region: unknown
+//! Zone:   ~
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(pipe(uppercase, prefix = "[", suffix = "]"))]`
+//!
+//! Transform the rendered value, applying `uppercase`, `lowercase`, `prefix` and `suffix` in order.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Check {
+//!     #[descriptor(pipe(uppercase, prefix = "[", suffix = "]"))]
+//!     status: String,
+//!     #[descriptor(pipe(suffix = " ms"))]
+//!     latency: u64,
+//! }
+//!
+//! let check = Check{status: "ok".to_string(), latency: 12};
+//! let description = object_describe_to_string(&check).unwrap();
+//! assert_eq!(r#"
+//! Status:  [OK]
+//! Latency: 12 ms
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(color = colorize)]`
+//!
+//! Call `fn(&str) -> String` on the rendered value, usually to add ANSI colors.
+//! Colors are ignored when computing the table columns width.
+//!
+//! ```
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! fn colorize(status: &str) -> String {
+//!     match status {
+//!         "ERROR" => format!("\x1b[31m{}\x1b[0m", status),
+//!         _ => status.to_string(),
+//!     }
+//! }
+//!
+//! #[derive(Descriptor)]
+//! struct Check {
+//!     #[descriptor(color = colorize)]
+//!     status: String,
+//!     name: String,
+//! }
+//!
+//! let checks = vec![
+//!     Check{status: "ERROR".to_string(), name: "disk".to_string()},
+//!     Check{status: "OK".to_string(), name: "network".to_string()},
+//! ];
+//! let table = table_describe_to_string(&checks).unwrap();
+//! assert_eq!(
+//!     "STATUS NAME\n\x1b[31mERROR\x1b[0m  disk\nOK     network\n",
+//!     table
+//! );
+//! ```
+//!
+//! ### `#[descriptor(max_width = 10)]`
+//!
+//! Truncate long values to the given number of chars, ending with `…`, in descriptions and tables.
+//!
+//! ```
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Issue {
+//!     #[descriptor(max_width = 10)]
+//!     title: String,
+//!     state: String,
+//! }
+//!
+//! let issues = vec![Issue{title: "Crash on startup".to_string(), state: "open".to_string()}];
+//! let table = table_describe_to_string(&issues).unwrap();
+//! assert_eq!(r#"
+//! TITLE      STATE
+//! Crash on … open
+//! "#,  format!("\n{}", table));
+//! ```
+//!
+//! ### `#[descriptor(min_width = 8)]`
+//!
+//! Minimum width of the column in tables, it can not exceed a `max_width`.
+//!
+//! ```
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Row {
+//!     #[descriptor(min_width = 8)]
+//!     id: u32,
+//!     name: String,
+//! }
+//!
+//! let rows = vec![Row{id: 1, name: "first".to_string()}];
+//! let table = table_describe_to_string(&rows).unwrap();
+//! assert_eq!(r#"
+//! ID       NAME
+//! 1        first
+//! "#,  format!("\n{}", table));
+//! ```
+//!
+//! ### `#[descriptor(spread = 3)]`
+//!
+//! Spread a `Vec` into indexed columns in tables, `tags_1` to `tags_3`.
+//! Missing elements are rendered as `~`.
+//!
+//! ```
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Car {
+//!     brand: String,
+//!     #[descriptor(spread = 3)]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let cars = vec![Car{brand: "Audi".to_string(), tags: vec!["fast".to_string(), "red".to_string()]}];
+//! let table = table_describe_to_string(&cars).unwrap();
+//! assert_eq!(r#"
+//! BRAND TAGS_1 TAGS_2 TAGS_3
+//! Audi  fast   red    ~
+//! "#,  format!("\n{}", table));
+//! ```
+//!
+//! ### `#[descriptor(key = "first_name")]`
+//!
+//! Key of the field in machine readable exports, like `object_describe_json_to_string`.
+//! The description and the tables keep using the display name.
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use descriptor::{Descriptor, object_describe_json_to_string, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct User {
+//!     #[descriptor(key = "first_name")]
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! let user = User{name: "Adrien".to_string(), age: 32};
+//! assert_eq!(
+//!     r#"{"age":"32","first_name":"Adrien"}"#,
+//!     object_describe_json_to_string(&user).unwrap()
+//! );
+//! let description = object_describe_to_string(&user).unwrap();
+//! assert_eq!(r#"
+//! Name: Adrien
+//! Age:  32
+//! "#,  description);
+//! # }
+//! ```
+//!
+//! ### `#[descriptor(wrap = 20)]`
+//!
+//! Wrap long values on lines of the given number of chars, table cells span multiple lines.
+//!
+//! ```
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Issue {
+//!     id: usize,
+//!     #[descriptor(wrap = 20)]
+//!     description: String,
+//!     state: String,
+//! }
+//!
+//! let issues = vec![Issue{
+//!     id: 1,
+//!     description: "The application crashes on startup".to_string(),
+//!     state: "open".to_string(),
+//! }];
+//! let table = table_describe_to_string(&issues).unwrap();
+//! assert_eq!(r#"
+//! ID DESCRIPTION        STATE
+//! 1  The application    open
+//!    crashes on startup
+//! "#,  format!("\n{}", table));
+//! ```
+//!
+//! ### `#[descriptor(order = 0)]`
+//!
+//! Move the field to the given position among the described fields, the other fields fill
+//! the remaining positions in their declaration order.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct User {
+//!     name: String,
+//!     email: String,
+//!     #[descriptor(order = 0)]
+//!     id: u32,
+//! }
+//!
+//! let user = User{name: "Adrien".to_string(), email: "adrien@xcid.fr".to_string(), id: 1};
+//! let description = object_describe_to_string(&user).unwrap();
+//! assert_eq!(r#"
+//! Id:    1
+//! Name:  Adrien
+//! Email: adrien@xcid.fr
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(skip_if_none)]`
+//!
+//! Omit an `Option` field from the description when it is `None`, tables keep the column.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct User {
+//!     name: String,
+//!     #[descriptor(skip_if_none)]
+//!     nickname: Option<String>,
+//! }
+//!
+//! let user = User{name: "Adrien".to_string(), nickname: None};
+//! let description = object_describe_to_string(&user).unwrap();
+//! assert_eq!(r#"
+//! Name: Adrien
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(skip_if_empty)]`
+//!
+//! Omit a `String` or a collection field from the description when it is empty.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct User {
+//!     name: String,
+//!     #[descriptor(skip_if_empty)]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let user = User{name: "Adrien".to_string(), tags: vec![]};
+//! let description = object_describe_to_string(&user).unwrap();
+//! assert_eq!(r#"
+//! Name: Adrien
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(display)]`
+//!
+//! Render the field with its `Display` implementation, the type does not need to implement `Describe`.
+//!
+//! Can be used with `resolve_option` for optional fields.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//! use std::fmt;
+//!
+//! struct Version(u32, u32);
+//!
+//! impl fmt::Display for Version {
+//!     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//!         write!(f, "v{}.{}", self.0, self.1)
+//!     }
+//! }
+//!
+//! #[derive(Descriptor)]
+//! struct Package {
+//!     name: String,
+//!     #[descriptor(display)]
+//!     version: Version,
+//! }
+//!
+//! let package = Package{name: "descriptor".to_string(), version: Version(0, 4)};
+//! let description = object_describe_to_string(&package).unwrap();
+//! assert_eq!(r#"
+//! Name:    descriptor
+//! Version: v0.4
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(debug)]`
+//!
+//! Render the field with its `Debug` implementation.
+//!
+//! Only one of `display`, `debug`, `date_format`, `bool_labels`, `precision`, `thousands`
+//! and `map`/`into` can be set on a field, the derive fails on any other combination.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Debug)]
+//! enum Level {
+//!     Warning,
+//! }
+//!
+//! #[derive(Descriptor)]
+//! struct Log {
+//!     #[descriptor(debug)]
+//!     level: Level,
+//!     #[descriptor(debug)]
+//!     message: String,
+//! }
+//!
+//! let log = Log{level: Level::Warning, message: "disk full".to_string()};
+//! let description = object_describe_to_string(&log).unwrap();
+//! assert_eq!(r#"
+//! Level:   Warning
+//! Message: "disk full"
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(join = "; ")]`
+//!
+//! Separator of the items of a `Vec` in a table cell, instead of `,`.
+//!
+//! ```
+//! use descriptor::{Descriptor, table_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Car {
+//!     brand: String,
+//!     #[descriptor(join = "; ")]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let cars = vec![Car{brand: "Audi".to_string(), tags: vec!["fast".to_string(), "red".to_string()]}];
+//! let table = table_describe_to_string(&cars).unwrap();
+//! assert_eq!(r#"
+//! BRAND TAGS
+//! Audi  fast; red
+//! "#,  format!("\n{}", table));
+//! ```
+//!
+//! ### `#[descriptor(numbered)]`
+//!
+//! Describe the items of a collection as a numbered list: `1.`, `2.`, ...
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Recipe {
+//!     #[descriptor(numbered)]
+//!     steps: Vec<String>,
+//! }
+//!
+//! let recipe = Recipe{steps: vec!["Boil".to_string(), "Serve".to_string()]};
+//! let description = object_describe_to_string(&recipe).unwrap();
+//! assert_eq!(r#"
+//! Steps:
+//! 1. Boil
+//! 2. Serve
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(bool_labels = "Yes/No")]`
+//!
+//! Render a `bool` with the given labels, the `true` one first.
+//!
+//! Can be used with `resolve_option` for `Option<bool>`.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Feature {
+//!     #[descriptor(bool_labels = "Yes/No")]
+//!     enabled: bool,
+//!     #[descriptor(bool_labels = "Yes/No", resolve_option)]
+//!     beta: Option<bool>,
+//! }
+//!
+//! let feature = Feature{enabled: true, beta: None};
+//! let description = object_describe_to_string(&feature).unwrap();
+//! assert_eq!(r#"
+//! Enabled: Yes
+//! Beta:    ~
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(precision = 2)]`
+//!
+//! Number of decimals of a `f32` or `f64` field.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Product {
+//!     #[descriptor(precision = 2)]
+//!     price: f64,
+//! }
+//!
+//! let description = object_describe_to_string(&Product{price: 9.5}).unwrap();
+//! assert_eq!(r#"
+//! Price: 9.50
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(thousands)]`
+//!
+//! Group the digits of a number by thousands: `1,234,567`, only the integer part of a float is grouped.
+//! Use `#[descriptor(thousands_sep = ".")]` for another separator.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Stats {
+//!     #[descriptor(thousands)]
+//!     visits: u64,
+//!     #[descriptor(thousands_sep = ".")]
+//!     revenue: u64,
+//! }
+//!
+//! let stats = Stats{visits: 1234567, revenue: 12345};
+//! let description = object_describe_to_string(&stats).unwrap();
+//! assert_eq!(r#"
+//! Visits:  1,234,567
+//! Revenue: 12.345
+//! "#,  description);
+//! ```
+//!
+//! ### `#[descriptor(show_sign)]`
+//!
+//! Always show the sign of a number, `5` is rendered as `+5`.
+//!
+//! Zero is rendered as `+0`, use `show_sign(signed_zero = false)` to render it as `0`.
+//!
+//! ```
+//! use descriptor::{Descriptor, object_describe_to_string};
+//!
+//! #[derive(Descriptor)]
+//! struct Delta {
+//!     #[descriptor(show_sign)]
+//!     gain: i32,
+//!     #[descriptor(show_sign)]
+//!     loss: i32,
+//!     #[descriptor(show_sign(signed_zero = false))]
+//!     change: i32,
+//! }
+//!
+//! let delta = Delta{gain: 5, loss: -3, change: 0};
+//! let description = object_describe_to_string(&delta).unwrap();
+//! assert_eq!(r#"
+//! Gain:   +5
+//! Loss:   -3
+//! Change: 0
+//! "#,  description);
+//! ```
+//!
+//! ## Enum parameters
+//! ### `#[descriptor(rename_description = "Renamed")]`
+//!
+//! Rename the value for enums.
+//!
+//! ```
+//! use descriptor::{object_describe_to_string, Descriptor};
+//! #[derive(Descriptor)]
+//! struct User {
+//!     name: String,
+//!     role: Role,
+//! }
+//! #[derive(Descriptor)]
+//! enum Role {
+//!     Admin,
+//!     #[descriptor(rename_description = "User role")]
+//!     User,
+//! }
+//!
+//! let description = object_describe_to_string(&User {
+//!    name: "Adrien".to_string(),
+//!    role: Role::User,
+//! }).unwrap();
+//! assert_eq!(r#"
+//! Name: Adrien
+//! Role: User role
+//! "#, description);
+//! ```
+//!
+//!
+pub mod helpers;
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ffi::{OsStr, OsString};
+use std::fmt::Display;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use convert_case::{Case, Casing};
+#[doc(hidden)]
+pub use descriptor_derive::{self, *};
+
+#[derive(Clone)]
+pub struct Context {
+    pub offset: usize,
+    pub pad: usize,
+    pub upper_pad: usize,
+    pub is_array: bool,
+    pub title_size: usize,
+    pub title_separator: String,
+    pub bullet: String,
+    pub indent_width: usize,
+    pub null_placeholder: String,
+    pub numbered: bool,
+    // Label of the current item in a numbered list, written in place of the bullet
+    pub item_label: Option<String>,
+    // Prepended to the titles of a flattened struct
+    pub title_prefix: String,
+    // Nesting level, nested values past `max_depth` are elided
+    pub depth: usize,
+    pub max_depth: usize,
+    pub header_case: HeaderCase,
+    // Width of the title column, overriding the width computed from the longest title
+    pub fixed_pad: Option<usize>,
+    // Fail on requested table headers that are not in `T::headers()`
+    pub strict_headers: bool,
+    // Whether the next title or list item starts on a new line, false for compact descriptions
+    pub leading_newline: bool,
+    // Colors and zebra rows are only rendered with the `color` feature
+    pub color: bool,
+    pub zebra: bool,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            pad: 0,
+            upper_pad: 0,
+            is_array: false,
+            title_size: 0,
+            title_separator: ":".to_string(),
+            bullet: "-".to_string(),
+            indent_width: 2,
+            null_placeholder: NULL_PLACEHOLDER.to_string(),
+            numbered: false,
+            item_label: None,
+            title_prefix: String::new(),
+            depth: 0,
+            max_depth: 64,
+            header_case: HeaderCase::default(),
+            fixed_pad: None,
+            strict_headers: false,
+            leading_newline: true,
+            color: false,
+            zebra: false,
+        }
+    }
+}
+
+impl Context {
+    // Root context starting at the given offset, other settings are the defaults
+    pub fn new(offset: usize, indent_width: usize) -> Self {
+        Self {
+            offset,
+            indent_width,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_title_separator(mut self, title_separator: &str) -> Self {
+        self.title_separator = title_separator.to_string();
+        self
+    }
+
+    pub fn with_bullet(mut self, bullet: &str) -> Self {
+        self.bullet = bullet.to_string();
+        self
+    }
+
+    pub fn with_indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_numbered(mut self, numbered: bool) -> Self {
+        self.numbered = numbered;
+        self
+    }
+
+    // Nested prefixes are concatenated, the prefix is kept until the next indentation
+    pub fn with_title_prefix(mut self, title_prefix: &str) -> Self {
+        self.title_prefix.push_str(title_prefix);
+        self
+    }
+
+    pub fn with_header_case(mut self, header_case: HeaderCase) -> Self {
+        self.header_case = header_case;
+        self
+    }
+
+    pub fn with_fixed_pad(mut self, fixed_pad: Option<usize>) -> Self {
+        self.fixed_pad = fixed_pad;
+        self
+    }
+
+    pub fn with_strict_headers(mut self, strict_headers: bool) -> Self {
+        self.strict_headers = strict_headers;
+        self
+    }
+
+    pub fn with_leading_newline(mut self, leading_newline: bool) -> Self {
+        self.leading_newline = leading_newline;
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn with_null_placeholder(mut self, null_placeholder: &str) -> Self {
+        self.null_placeholder = null_placeholder.to_string();
+        self
+    }
+
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_zebra(mut self, zebra: bool) -> Self {
+        self.zebra = zebra;
+        self
+    }
+
+    // Colors require the `color` feature and are disabled by the NO_COLOR environment variable
+    fn colored(&self) -> bool {
+        let no_color = matches!(std::env::var_os("NO_COLOR"), Some(value) if !value.is_empty());
+        cfg!(feature = "color") && self.color && !no_color
+    }
+
+    // Wrap the text in the given ANSI style, unless colors are disabled
+    fn style(&self, text: &str, style: &str) -> String {
+        if self.colored() {
+            format!("\x1b[{}m{}\x1b[0m", style, text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    // Start and end of a table row, every other row gets a background with zebra
+    fn stripe(&self, row: usize) -> (String, &'static str) {
+        if self.zebra && row % 2 == 1 && self.colored() {
+            (format!("\x1b[{}m", STRIPE), "\x1b[0m")
+        } else {
+            (String::new(), "")
+        }
+    }
+
+    // Whether the value is nested too deep to be described, e.g. in a cyclic structure
+    pub fn depth_exceeded(&self) -> bool {
+        self.depth > self.max_depth
+    }
+
+    // Write the placeholder of a missing value, dimmed so real values stand out
+    pub fn write_null<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_value(writer, self.style(&self.null_placeholder, DIM))
+    }
+
+    // Write the bullet so the item content starts at the current offset
+    fn write_bullet<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let bullet = self.item_label.as_ref().unwrap_or(&self.bullet);
+        let bullet_width = display_width(bullet);
+        let width = self.indent_width.max(bullet_width + 1);
+        write!(
+            writer,
+            "{:<offset$}{}{:<pad$}",
+            "",
+            bullet,
+            "",
+            offset = self.offset.saturating_sub(width),
+            pad = width - bullet_width
+        )
+    }
+
+    pub fn pad(&self, upper_pad: usize) -> Self {
+        Self {
+            upper_pad: self.upper_pad.max(upper_pad),
+            pad: 0,
+            title_size: 0,
+            is_array: false,
+            ..self.clone()
+        }
+    }
+
+    pub fn indent(&self, pad: usize, title_size: usize) -> Self {
+        let title_size = title_size + self.title_prefix.len();
+        // Longer titles overflow the fixed column by a single space
+        let pad = match self.fixed_pad {
+            Some(fixed_pad) => fixed_pad.max(title_size + 1),
+            None => pad.max(self.upper_pad),
+        };
+        Self {
+            offset: self.offset + self.indent_width,
+            pad,
+            upper_pad: 0,
+            title_size,
+            is_array: false,
+            numbered: false,
+            item_label: None,
+            title_prefix: String::new(),
+            depth: self.depth + 1,
+            leading_newline: true,
+            ..self.clone()
+        }
+    }
+
+    pub fn array(&self) -> Self {
+        Self {
+            title_size: 0,
+            upper_pad: 0,
+            is_array: true,
+            numbered: false,
+            item_label: None,
+            title_prefix: String::new(),
+            depth: self.depth + 1,
+            ..self.clone()
+        }
+    }
+
+    // Context of the item `idx` of a list of `len` items, numbered lists are shifted to fit the label
+    fn array_item(&self, idx: usize, len: usize) -> Self {
+        // Only the first item may skip its line break
+        let item = Self {
+            leading_newline: self.leading_newline || idx > 0,
+            ..self.array()
+        };
+        if !self.numbered {
+            return item;
+        }
+        let label = format!("{:>width$}.", idx + 1, width = len.to_string().len());
+        let extra = (label.len() + 1).saturating_sub(self.indent_width);
+        Self {
+            offset: self.offset + extra,
+            item_label: Some(label),
+            ..item
+        }
+    }
+
+    pub fn indent_and_table(&self) -> Self {
+        Self {
+            offset: self.offset + self.indent_width,
+            pad: 0,
+            upper_pad: 0,
+            title_size: 0,
+            is_array: true,
+            numbered: false,
+            item_label: None,
+            title_prefix: String::new(),
+            depth: self.depth + 1,
+            leading_newline: true,
+            ..self.clone()
+        }
+    }
+
+    pub fn describe_table<T, W>(&self, data: &[T], writer: &mut W) -> io::Result<()>
+    where
+        T: Describe,
+        W: io::Write,
+    {
+        self.describe_table_with_header(data, &[], writer)
+    }
+
+    pub fn describe_table_with_header<T, W>(
+        &self,
+        data: &[T],
+        headers: &[String],
+        writer: &mut W,
+    ) -> io::Result<()>
+    where
+        T: Describe,
+        W: io::Write,
+    {
+        writeln!(writer)?;
+        Describer::describe_list_internal(
+            data,
+            headers,
+            None,
+            None,
+            writer,
+            self.indent_and_table(),
+        )
+    }
+
+    pub fn write_title<W>(&self, writer: &mut W, field: &str, first_field: bool) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        if self.leading_newline {
+            writeln!(writer)?;
+        }
+        let offset = if first_field && self.is_array {
+            self.write_bullet(writer)?;
+            0
+        } else {
+            self.offset
+        };
+
+        write!(
+            writer,
+            "{:<offset$}{}{}",
+            "",
+            self.style(&format!("{}{}", self.title_prefix, field), DIM),
+            self.title_separator,
+            offset = offset
+        )
+    }
+
+    pub fn write_value<W>(&self, writer: &mut W, field: String) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        if self.is_array {
+            if self.leading_newline {
+                writeln!(writer)?;
+            }
+            self.write_bullet(writer)?;
+            write!(writer, "{}", field)
+        } else {
+            // Every title is followed by the same separator, so the pad does not depend on it
+            write!(
                 writer,
                 "{:>pad$}{}",
                 "",
@@ -556,6 +1650,326 @@ impl Context {
     }
 }
 
+/// Placeholder rendered for missing values, like `None` or empty collections
+pub const NULL_PLACEHOLDER: &str = "~";
+
+// ANSI styles used when the `color` feature is enabled
+const BOLD: &str = "1";
+const DIM: &str = "2";
+const STRIPE: &str = "48;5;236";
+
+/// Options of the description, see `object_describe_with_options` and `table_describe_with_options`
+#[derive(Clone, Debug)]
+pub struct DescribeOptions {
+    /// Rendered for missing values, like `None` or empty collections
+    pub null_placeholder: String,
+    /// Written after every title
+    pub title_separator: String,
+    /// Number of spaces per nesting level
+    pub indent_width: usize,
+    /// Written before every item of a list
+    pub bullet: String,
+    /// Casing of the table headers that are not renamed
+    pub header_case: HeaderCase,
+    /// Align the values of every description at this title width instead of the longest title
+    pub fixed_pad: Option<usize>,
+    /// Fail with an `InvalidInput` error on requested table headers that are not in `T::headers()`
+    pub strict_headers: bool,
+    /// Render titles dimmed and table headers bold, the `NO_COLOR` environment variable disables it.
+    /// Ignored without the `color` feature
+    pub color: bool,
+    /// Add a background to every other table row, requires `color`
+    pub zebra: bool,
+}
+
+impl Default for DescribeOptions {
+    fn default() -> Self {
+        let ctx = Context::default();
+        Self {
+            null_placeholder: ctx.null_placeholder,
+            title_separator: ctx.title_separator,
+            indent_width: ctx.indent_width,
+            bullet: ctx.bullet,
+            header_case: ctx.header_case,
+            fixed_pad: ctx.fixed_pad,
+            strict_headers: ctx.strict_headers,
+            color: ctx.color,
+            zebra: ctx.zebra,
+        }
+    }
+}
+
+impl DescribeOptions {
+    /// The root context of a description using these options
+    pub fn context(&self) -> Context {
+        Context::default()
+            .with_null_placeholder(&self.null_placeholder)
+            .with_title_separator(&self.title_separator)
+            .with_indent_width(self.indent_width)
+            .with_bullet(&self.bullet)
+            .with_header_case(self.header_case)
+            .with_fixed_pad(self.fixed_pad)
+            .with_strict_headers(self.strict_headers)
+            .with_color(self.color)
+            .with_zebra(self.zebra)
+    }
+}
+
+/// Casing applied to field names in table headers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderCase {
+    /// `FIRST_NAME`, the default
+    UpperSnake,
+    /// `First Name`
+    Title,
+    /// `first name`
+    Lower,
+    /// `first_name`, the field name as is
+    Raw,
+    /// `first-name`
+    Kebab,
+}
+
+// `#[default]` on enum variants requires Rust 1.62
+#[allow(clippy::derivable_impls)]
+impl Default for HeaderCase {
+    fn default() -> Self {
+        HeaderCase::UpperSnake
+    }
+}
+
+impl HeaderCase {
+    pub fn apply(self, header: &str) -> String {
+        match self {
+            HeaderCase::UpperSnake => header.to_case(Case::UpperSnake),
+            HeaderCase::Title => header.to_case(Case::Title),
+            HeaderCase::Lower => header.to_case(Case::Lower),
+            HeaderCase::Raw => header.to_string(),
+            HeaderCase::Kebab => header.to_case(Case::Kebab),
+        }
+    }
+}
+
+// Written in place of the rows of an empty table
+const EMPTY_LIST: &str = "Empty list";
+
+/// Separator used to join multiple values in a single table cell
+pub const DEFAULT_SEPARATOR: &str = ",";
+
+// Render a value as compact JSON, used by the `json_inline` attribute
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub fn json_inline<T: serde::Serialize + ?Sized>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_else(|e| e.to_string())
+}
+
+// Called by the derive for the `json_inline` attribute
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! json_inline {
+    ($value: expr) => {
+        $crate::json_inline($value)
+    };
+}
+
+// Without the `serde` feature the attribute fails with a clear message
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! json_inline {
+    ($value: expr) => {
+        compile_error!("`json_inline` requires the `serde` feature of descriptor")
+    };
+}
+
+/// Timestamps usable with the `duration_between` attribute
+pub trait Timestamp {
+    // Return the number of seconds since the unix epoch, None if the timestamp is missing
+    fn unix_seconds(&self) -> Option<i64>;
+}
+
+impl Timestamp for SystemTime {
+    fn unix_seconds(&self) -> Option<i64> {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(duration) => Some(duration.as_secs() as i64),
+            Err(e) => Some(-(e.duration().as_secs() as i64)),
+        }
+    }
+}
+
+impl<T: Timestamp> Timestamp for Option<T> {
+    fn unix_seconds(&self) -> Option<i64> {
+        self.as_ref().and_then(T::unix_seconds)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Timestamp for chrono::DateTime<Tz> {
+    fn unix_seconds(&self) -> Option<i64> {
+        Some(self.timestamp())
+    }
+}
+
+// Render the elapsed time between two timestamps, used by the `duration_between` attribute
+// None when a timestamp is missing
+#[doc(hidden)]
+pub fn duration_between<S: Timestamp, E: Timestamp>(start: &S, end: &E) -> Option<String> {
+    match (start.unix_seconds(), end.unix_seconds()) {
+        (Some(start), Some(end)) if end < start => Some(format!(
+            "-{}",
+            helpers::humanize_duration(&start.abs_diff(end))
+        )),
+        (Some(start), Some(end)) => Some(helpers::humanize_duration(&end.abs_diff(start))),
+        _ => None,
+    }
+}
+
+// Prefix non-negative numbers with `+`, used by the `show_sign` attribute
+#[doc(hidden)]
+pub fn show_sign<T: Display + PartialOrd + Default>(value: T, signed_zero: bool) -> String {
+    let zero = T::default();
+    let positive = value > zero || (signed_zero && value == zero);
+    let value = value.to_string();
+    // `-0.0` equals zero but is already signed
+    if positive && !value.starts_with('-') {
+        format!("+{}", value)
+    } else {
+        value
+    }
+}
+
+// Group the digits of a number by thousands, used by the `thousands` attribute
+// Only the integer part of a float is grouped
+#[doc(hidden)]
+pub fn group_thousands<T: Display>(value: T, separator: &str) -> String {
+    let value = value.to_string();
+    let (sign, number) = match value.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", value.as_str()),
+    };
+    let (digits, fraction) = match number.find('.') {
+        Some(idx) => number.split_at(idx),
+        None => (number, ""),
+    };
+    let digits = digits.chars().collect::<Vec<_>>();
+    let groups = digits
+        .rchunks(3)
+        .rev()
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<_>>();
+    format!("{}{}{}", sign, groups.join(separator), fraction)
+}
+
+// Width of a value on a terminal, ignoring ANSI codes: combining chars take no column
+// and wide chars like CJK take two
+fn display_width(value: &str) -> usize {
+    String::from_utf8(strip_ansi_escapes::strip(value).unwrap())
+        .unwrap_or_else(|_| value.to_string())
+        .width()
+}
+
+// Truncate a value to `max_width` columns ending with `…`, used by the `max_width` attribute
+// ANSI escape sequences are kept and do not count in the width
+#[doc(hidden)]
+pub fn truncate(value: &str, max_width: usize) -> String {
+    if display_width(value) <= max_width {
+        return value.to_string();
+    }
+
+    let mut truncated = String::with_capacity(value.len());
+    let mut width = 0;
+    let mut escaped = false;
+    let mut rest = value;
+    'cut: while !rest.is_empty() {
+        let (text, sequence) = rest.split_at(rest.find('\x1b').unwrap_or(rest.len()));
+        // Whole graphemes only, a combining mark or a ZWJ sequence is never split
+        for grapheme in text.graphemes(true) {
+            if width + grapheme.width() >= max_width {
+                break 'cut;
+            }
+            truncated.push_str(grapheme);
+            width += grapheme.width();
+        }
+        // Copy the whole escape sequence, up to its final letter
+        let end = sequence
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_ascii_alphabetic())
+            .map_or(sequence.len(), |(idx, _)| idx + 1);
+        escaped |= end > 0;
+        truncated.push_str(&sequence[..end]);
+        rest = &sequence[end..];
+    }
+    truncated.push('…');
+    // Styles opened before the cut are closed
+    if escaped {
+        truncated.push_str("\x1b[0m");
+    }
+    truncated
+}
+
+// Wrap a value on lines of at most `width` chars, used by the `wrap` attribute
+// Lines are broken between words when possible, existing line breaks are kept
+#[doc(hidden)]
+pub fn wrap(value: &str, width: usize) -> String {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for text in value.split('\n') {
+        let mut line = String::new();
+        let mut line_width = 0;
+        for word in text.split(' ') {
+            let mut word = word.chars().collect::<Vec<_>>();
+            if line_width > 0 && line_width + 1 + word.len() > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            // Words longer than a line are cut
+            while word.len() > width {
+                let rest = word.split_off(width);
+                line.extend(word);
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+                word = rest;
+            }
+            if line_width > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+            line_width += word.len();
+            line.extend(word);
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+thread_local! {
+    // Types whose headers are being listed, in nesting order
+    static HEADERS_STACK: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+// List the headers of `T`, or none when `T` is already being listed, cutting recursive types
+#[doc(hidden)]
+pub fn headers_guard<T: ?Sized, F: FnOnce() -> Vec<String>>(headers: F) -> Vec<String> {
+    let name = std::any::type_name::<T>();
+    let cyclic = HEADERS_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let cyclic = stack.contains(&name);
+        if !cyclic {
+            stack.push(name);
+        }
+        cyclic
+    });
+    if cyclic {
+        return Vec::new();
+    }
+
+    let headers = headers();
+    HEADERS_STACK.with(|stack| stack.borrow_mut().pop());
+    headers
+}
+
 #[doc(hidden)]
 pub fn get_keys(field_name: &str) -> (&str, &str) {
     match field_name.split_once(".") {
@@ -564,102 +1978,551 @@ pub fn get_keys(field_name: &str) -> (&str, &str) {
     }
 }
 
+// Return the child header of a header produced by a field flattened with `prefix`
+#[doc(hidden)]
+pub fn flattened_header<'a, T: Describe>(header: &'a str, prefix: &str) -> Option<&'a str> {
+    header
+        .strip_prefix(prefix)
+        .filter(|child| T::headers().iter().any(|x| x == child))
+}
+
 pub trait Describe {
     // Method that take a field name and should return a String value of the field.
     // This method extract keys with dot in order to call the to_field method for children
     fn to_field(&self, field_name: &str) -> String;
 
-    // Return the default_headers for the structs
-    fn default_headers() -> Vec<String> {
-        Self::headers()
+    // Same as to_field, with missing values rendered as `null_placeholder` instead of `NULL_PLACEHOLDER`
+    fn to_field_with_null(&self, field_name: &str, _: &str) -> String {
+        self.to_field(field_name)
+    }
+
+    // Return the default_headers for the structs
+    fn default_headers() -> Vec<String> {
+        Self::headers()
+    }
+
+    // Return the list of all headers for the struct
+    fn headers() -> Vec<String> {
+        vec![]
+    }
+
+    // Return another name for an header
+    fn header_name(_: &str) -> Option<String> {
+        None
+    }
+
+    // Return the key used by machine readable exports for an header, if it differs
+    fn machine_key(_: &str) -> Option<String> {
+        None
+    }
+
+    // Return the minimum width of the column of an header in tables
+    fn min_width(_: &str) -> usize {
+        0
+    }
+
+    // Return the label displayed in the table header for a field
+    fn label_for(field: &str) -> String {
+        Self::label_with_case(field, HeaderCase::default())
+    }
+
+    // Return the label of a field, the renamed header or the field name in the given case
+    fn label_with_case(field: &str, header_case: HeaderCase) -> String {
+        Self::header_name(field).unwrap_or_else(|| header_case.apply(field))
+    }
+
+    fn struct_pad() -> usize {
+        0
+    }
+
+    // Return true for collections described as a list
+    #[doc(hidden)]
+    fn is_collection() -> bool {
+        false
+    }
+
+    // Return every field of the struct as a pair of dotted header and value
+    fn describe_pairs(&self) -> Vec<(String, String)> {
+        Self::headers()
+            .into_iter()
+            .map(|header| {
+                let value = self.to_field(&header);
+                (header, value)
+            })
+            .collect()
+    }
+
+    // Return every field of the struct keyed by its dotted header
+    fn to_field_map(&self) -> HashMap<String, String> {
+        self.describe_pairs().into_iter().collect()
+    }
+
+    // Describe write the current description of the struct
+    // The current version is used for scalar types
+    fn describe<W>(&self, writer: &mut W, ctx: Context) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        ctx.write_value(writer, self.to_field(""))
+    }
+
+    // Shortcut for object_describe_to_string
+    fn describe_to_string(&self) -> io::Result<String>
+    where
+        Self: Sized,
+    {
+        object_describe_to_string(self)
+    }
+}
+
+// Describe the entries of a map in the given order, values are aligned on the longest key
+fn describe_map<'a, W, K, V>(
+    entries: Vec<(&'a K, &'a V)>,
+    writer: &mut W,
+    ctx: Context,
+) -> io::Result<()>
+where
+    W: io::Write,
+    K: Display + 'a,
+    V: Describe + 'a,
+{
+    if !entries.is_empty() {
+        let entries = entries
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<Vec<_>>();
+        let pad = entries
+            .iter()
+            .map(|(k, _)| k.len())
+            .max()
+            .unwrap_or_default()
+            + 1;
+        let mut ctx = ctx;
+        for (k, v) in entries {
+            ctx.write_title(writer, &k, false)?;
+            v.describe(writer, ctx.indent(pad, k.len()))?;
+            ctx.leading_newline = true;
+        }
+    } else {
+        ctx.write_null(writer)?
+    }
+    Ok(())
+}
+
+impl<K: Display + Ord, V: Describe> Describe for HashMap<K, V> {
+    fn to_field(&self, _: &str) -> String {
+        "todo".to_string()
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(k, _)| *k);
+        describe_map(entries, writer, ctx)
+    }
+}
+
+impl<K: Display + Ord, V: Describe> Describe for BTreeMap<K, V> {
+    fn to_field(&self, _: &str) -> String {
+        "todo".to_string()
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        describe_map(self.iter().collect(), writer, ctx)
+    }
+}
+
+// Entries keep their insertion order
+#[cfg(feature = "indexmap")]
+impl<K: Display, V: Describe> Describe for indexmap::IndexMap<K, V> {
+    fn to_field(&self, _: &str) -> String {
+        "todo".to_string()
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        describe_map(self.iter().collect(), writer, ctx)
+    }
+}
+
+// Objects and arrays are described like maps and lists, fields are looked up by key
+#[cfg(feature = "serde_json")]
+impl Describe for serde_json::Value {
+    fn to_field(&self, field_name: &str) -> String {
+        self.to_field_with_null(field_name, NULL_PLACEHOLDER)
+    }
+
+    fn to_field_with_null(&self, field_name: &str, null_placeholder: &str) -> String {
+        if !field_name.is_empty() {
+            let (key, child) = get_keys(field_name);
+            return match self.get(key) {
+                Some(value) => value.to_field_with_null(child, null_placeholder),
+                None => null_placeholder.to_string(),
+            };
+        }
+        match self {
+            serde_json::Value::Null => null_placeholder.to_string(),
+            serde_json::Value::String(value) => value.clone(),
+            serde_json::Value::Array(values) => list_to_field(values.iter(), "", null_placeholder),
+            value => value.to_string(),
+        }
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        match self {
+            serde_json::Value::Null => ctx.write_null(writer),
+            serde_json::Value::Object(map) => describe_map(map.iter().collect(), writer, ctx),
+            serde_json::Value::Array(values) => describe_list(values.iter(), writer, ctx),
+            value => ctx.write_value(writer, value.to_field("")),
+        }
+    }
+}
+
+// Join the field of every item, used by collections in tables
+fn list_to_field<'a, T, I>(items: I, field: &str, null_placeholder: &str) -> String
+where
+    T: Describe + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    items
+        .map(|x| x.to_field_with_null(field, null_placeholder))
+        .collect::<Vec<_>>()
+        .join(DEFAULT_SEPARATOR)
+}
+
+// Describe every item of a collection as a bulleted or numbered list
+fn describe_list<'a, W, T, I>(items: I, writer: &mut W, ctx: Context) -> io::Result<()>
+where
+    W: io::Write,
+    T: Describe + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    let items = items.collect::<Vec<_>>();
+    if items.is_empty() {
+        ctx.write_null(writer)
+    } else {
+        for (idx, inner) in items.iter().enumerate() {
+            inner.describe(writer, ctx.array_item(idx, items.len()))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Describe> Describe for Vec<T> {
+    fn is_collection() -> bool {
+        true
+    }
+
+    fn to_field(&self, field: &str) -> String {
+        self.to_field_with_null(field, NULL_PLACEHOLDER)
+    }
+
+    fn to_field_with_null(&self, field: &str, null_placeholder: &str) -> String {
+        list_to_field(self.iter(), field, null_placeholder)
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        describe_list(self.iter(), writer, ctx)
+    }
+}
+
+impl<T: Describe, const N: usize> Describe for [T; N] {
+    fn is_collection() -> bool {
+        true
+    }
+
+    fn to_field(&self, field: &str) -> String {
+        self.to_field_with_null(field, NULL_PLACEHOLDER)
+    }
+
+    fn to_field_with_null(&self, field: &str, null_placeholder: &str) -> String {
+        list_to_field(self.iter(), field, null_placeholder)
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        describe_list(self.iter(), writer, ctx)
+    }
+}
+
+impl<T: Describe> Describe for [T] {
+    fn is_collection() -> bool {
+        true
+    }
+
+    fn to_field(&self, field: &str) -> String {
+        self.to_field_with_null(field, NULL_PLACEHOLDER)
+    }
+
+    fn to_field_with_null(&self, field: &str, null_placeholder: &str) -> String {
+        list_to_field(self.iter(), field, null_placeholder)
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        describe_list(self.iter(), writer, ctx)
+    }
+}
+
+// Items are sorted to get a deterministic output
+impl<T: Describe + Ord> Describe for HashSet<T> {
+    fn is_collection() -> bool {
+        true
+    }
+
+    fn to_field(&self, field: &str) -> String {
+        self.to_field_with_null(field, NULL_PLACEHOLDER)
+    }
+
+    fn to_field_with_null(&self, field: &str, null_placeholder: &str) -> String {
+        let mut items = self.iter().collect::<Vec<_>>();
+        items.sort();
+        list_to_field(items.into_iter(), field, null_placeholder)
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        let mut items = self.iter().collect::<Vec<_>>();
+        items.sort();
+        describe_list(items.into_iter(), writer, ctx)
+    }
+}
+
+impl<T: Describe> Describe for BTreeSet<T> {
+    fn is_collection() -> bool {
+        true
+    }
+
+    fn to_field(&self, field: &str) -> String {
+        self.to_field_with_null(field, NULL_PLACEHOLDER)
+    }
+
+    fn to_field_with_null(&self, field: &str, null_placeholder: &str) -> String {
+        list_to_field(self.iter(), field, null_placeholder)
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        describe_list(self.iter(), writer, ctx)
+    }
+}
+
+impl<T: Describe> Describe for Option<T> {
+    fn to_field(&self, field_name: &str) -> String {
+        self.to_field_with_null(field_name, NULL_PLACEHOLDER)
+    }
+
+    fn to_field_with_null(&self, field_name: &str, null_placeholder: &str) -> String {
+        match self {
+            None => null_placeholder.to_string(),
+            Some(v) => v.to_field_with_null(field_name, null_placeholder),
+        }
+    }
+
+    fn headers() -> Vec<String> {
+        T::headers()
+    }
+
+    fn header_name(header: &str) -> Option<String> {
+        T::header_name(header)
+    }
+
+    fn machine_key(header: &str) -> Option<String> {
+        T::machine_key(header)
+    }
+
+    fn min_width(header: &str) -> usize {
+        T::min_width(header)
+    }
+
+    fn struct_pad() -> usize {
+        T::struct_pad()
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        match self {
+            None => ctx.write_null(writer),
+            Some(v) => v.describe(writer, ctx),
+        }
+    }
+}
+
+// Headers target the `Ok` value, errors are only rendered as a single field
+impl<T: Describe, E: Describe> Describe for Result<T, E> {
+    fn to_field(&self, field_name: &str) -> String {
+        self.to_field_with_null(field_name, NULL_PLACEHOLDER)
+    }
+
+    fn to_field_with_null(&self, field_name: &str, null_placeholder: &str) -> String {
+        match self {
+            Ok(value) => value.to_field_with_null(field_name, null_placeholder),
+            Err(err) => format!(
+                "Error: {}",
+                err.to_field_with_null(field_name, null_placeholder)
+            ),
+        }
+    }
+
+    fn headers() -> Vec<String> {
+        T::headers()
+    }
+
+    fn header_name(header: &str) -> Option<String> {
+        T::header_name(header)
+    }
+
+    fn machine_key(header: &str) -> Option<String> {
+        T::machine_key(header)
+    }
+
+    fn min_width(header: &str) -> usize {
+        T::min_width(header)
+    }
+
+    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+        match self {
+            Ok(value) => value.describe(writer, ctx),
+            Err(err) => err.describe(writer, ctx),
+        }
+    }
+}
+
+// Headers target the `Continue` value, the usual case
+impl<B: Describe, C: Describe> Describe for ControlFlow<B, C> {
+    fn to_field(&self, field_name: &str) -> String {
+        match self {
+            ControlFlow::Continue(c) => format!("Continue({})", c.to_field(field_name)),
+            ControlFlow::Break(b) => format!("Break({})", b.to_field(field_name)),
+        }
+    }
+
+    fn headers() -> Vec<String> {
+        C::headers()
+    }
+
+    fn header_name(header: &str) -> Option<String> {
+        C::header_name(header)
+    }
+}
+
+impl Describe for str {
+    fn to_field(&self, _: &str) -> String {
+        self.to_string()
     }
+}
 
-    // Return the list of all headers for the struct
-    fn headers() -> Vec<String> {
-        vec![]
+impl Describe for Cow<'_, str> {
+    fn to_field(&self, _: &str) -> String {
+        self.to_string()
     }
+}
 
-    // Return another name for an header
-    fn header_name(_: &str) -> Option<String> {
-        None
+// Non UTF-8 strings are displayed lossily
+impl Describe for OsStr {
+    fn to_field(&self, _: &str) -> String {
+        self.to_string_lossy().into_owned()
     }
+}
 
-    fn struct_pad() -> usize {
-        0
+impl Describe for OsString {
+    fn to_field(&self, _: &str) -> String {
+        self.to_string_lossy().into_owned()
     }
+}
 
-    // Describe write the current description of the struct
-    // The current version is used for scalar types
-    fn describe<W>(&self, writer: &mut W, ctx: Context) -> io::Result<()>
-    where
-        W: io::Write,
-    {
-        ctx.write_value(writer, self.to_field(""))
+// Non UTF-8 paths are displayed lossily
+impl Describe for Path {
+    fn to_field(&self, _: &str) -> String {
+        self.display().to_string()
     }
 }
 
-impl<V: Describe> Describe for HashMap<String, V> {
+impl Describe for PathBuf {
     fn to_field(&self, _: &str) -> String {
-        "todo".to_string()
+        self.display().to_string()
     }
+}
 
-    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
-        if !self.is_empty() {
-            let pad = &self.keys().map(|k| k.len()).max().unwrap_or_default() + 1;
-            let mut keys = self.keys().collect::<Vec<_>>();
-            keys.sort();
-            for k in keys {
-                ctx.write_title(writer, k, false)?;
-                self[k].describe(writer, ctx.indent(pad, k.len()))?;
-            }
+// The offset is omitted for UTC dates
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Describe for chrono::DateTime<Tz>
+where
+    Tz::Offset: Display,
+{
+    fn to_field(&self, _: &str) -> String {
+        use chrono::Offset;
+
+        let date = self.format("%d-%m-%y %H:%M:%S");
+        if self.offset().fix().local_minus_utc() == 0 {
+            date.to_string()
         } else {
-            ctx.write_value(writer, "~".to_string())?
+            format!("{} {}", date, self.offset())
         }
-        Ok(())
     }
 }
 
-impl<T: Describe> Describe for Vec<T> {
-    fn to_field(&self, field: &str) -> String {
-        self.iter()
-            .map(|x| x.to_field(field))
-            .collect::<Vec<_>>()
-            .join(",")
+// ISO 8601 in UTC, times before the epoch included
+#[cfg(feature = "chrono")]
+impl Describe for SystemTime {
+    fn to_field(&self, _: &str) -> String {
+        chrono::DateTime::<chrono::Utc>::from(*self)
+            .format("%Y-%m-%dT%H:%M:%SZ")
+            .to_string()
     }
+}
 
-    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
-        if self.is_empty() {
-            ctx.write_value(writer, "~".to_string())
-        } else {
-            for inner in self {
-                inner.describe(writer, ctx.array())?;
-            }
-            Ok(())
-        }
+#[cfg(feature = "chrono")]
+impl Describe for chrono::NaiveDate {
+    fn to_field(&self, _: &str) -> String {
+        self.format("%Y-%m-%d").to_string()
     }
 }
 
-impl<T: Describe> Describe for Option<T> {
-    fn to_field(&self, field_name: &str) -> String {
-        match self {
-            None => "~".to_string(),
-            Some(v) => v.to_field(field_name),
-        }
+#[cfg(feature = "chrono")]
+impl Describe for chrono::NaiveDateTime {
+    fn to_field(&self, _: &str) -> String {
+        self.format("%Y-%m-%d %H:%M:%S").to_string()
     }
+}
 
-    fn headers() -> Vec<String> {
-        T::headers()
+#[cfg(feature = "chrono")]
+impl Describe for chrono::NaiveTime {
+    fn to_field(&self, _: &str) -> String {
+        self.format("%H:%M:%S").to_string()
     }
+}
 
-    fn header_name(header: &str) -> Option<String> {
-        T::header_name(header)
+// Hyphenated lowercase, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`
+#[cfg(feature = "uuid")]
+impl Describe for uuid::Uuid {
+    fn to_field(&self, _: &str) -> String {
+        self.hyphenated().to_string()
     }
+}
 
-    fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
-        match self {
-            None => ctx.write_value(writer, "~".to_string()),
-            Some(v) => v.describe(writer, ctx),
+impl Describe for ExitStatus {
+    fn to_field(&self, _: &str) -> String {
+        if let Some(code) = self.code() {
+            return format!("exit code {}", code);
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = self.signal() {
+                return format!("signal {}", signal);
+            }
         }
+        self.to_string()
+    }
+}
+
+/// Describe any `Display` type through its `to_string()`, for external types lacking an impl.
+///
+/// A blanket `impl<T: Display> Describe for T` would conflict with the impls of this crate
+/// (`String`, numbers, addresses…) and forbid user impls on their own `Display` types, so the
+/// value has to be wrapped explicitly: `DescribeViaDisplay(value)`.
+#[cfg(feature = "display-fallback")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DescribeViaDisplay<T>(pub T);
+
+#[cfg(feature = "display-fallback")]
+impl<T: Display> Describe for DescribeViaDisplay<T> {
+    fn to_field(&self, _: &str) -> String {
+        self.0.to_string()
     }
 }
 
@@ -678,6 +2541,18 @@ impl Describer {
         writeln!(writer)
     }
 
+    // Same as `describe_object` without the line break written before the first field
+    pub fn describe_object_compact<W: io::Write, T>(
+        data: &T,
+        writer: &mut W,
+        ctx: Context,
+    ) -> io::Result<()>
+    where
+        T: Describe,
+    {
+        Self::describe_object(data, writer, ctx.with_leading_newline(false))
+    }
+
     pub fn describe_list<W: io::Write, T>(
         data: &[T],
         writer: &mut W,
@@ -698,19 +2573,351 @@ impl Describer {
     where
         T: Describe,
     {
-        Self::describe_list_internal(data, headers, writer, ctx)?;
+        Self::describe_list_internal(data, headers, None, None, writer, ctx)?;
+        writeln!(writer)
+    }
+
+    pub fn describe_list_limited<W: io::Write, T>(
+        data: &[T],
+        max_rows: usize,
+        writer: &mut W,
+        ctx: Context,
+    ) -> io::Result<()>
+    where
+        T: Describe,
+    {
+        let shown = &data[..data.len().min(max_rows)];
+        Self::describe_list_internal(shown, &[], None, None, writer, ctx)?;
+        if data.len() > shown.len() {
+            writeln!(writer)?;
+            write!(writer, "… and {} more", data.len() - shown.len())?;
+        }
+        writeln!(writer)
+    }
+
+    pub fn describe_oneline<W: io::Write, T>(data: &T, writer: &mut W) -> io::Result<()>
+    where
+        T: Describe,
+    {
+        for (idx, (key, value)) in data.describe_pairs().into_iter().enumerate() {
+            if idx > 0 {
+                write!(writer, " ")?;
+            }
+            if value.is_empty() || value.contains(char::is_whitespace) {
+                write!(writer, "{}={:?}", key, value)?;
+            } else {
+                write!(writer, "{}={}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn describe_ledger<W: io::Write, T>(
+        data: &T,
+        width: usize,
+        writer: &mut W,
+    ) -> io::Result<()>
+    where
+        T: Describe,
+    {
+        for (key, value) in data.describe_pairs() {
+            let title = T::label_with_case(&key, HeaderCase::Title);
+            let used = display_width(&title) + display_width(&value);
+            writeln!(
+                writer,
+                "{}{:width$}{}",
+                title,
+                "",
+                value,
+                width = width.saturating_sub(used).max(1)
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn describe_list_fit<W: io::Write, T>(
+        data: &[T],
+        term_width: usize,
+        writer: &mut W,
+        ctx: Context,
+    ) -> io::Result<()>
+    where
+        T: Describe,
+    {
+        Self::describe_list_internal(data, &[], None, Some(term_width), writer, ctx)?;
+        writeln!(writer)
+    }
+
+    pub fn describe_list_with_footer<W: io::Write, T>(
+        data: &[T],
+        footer: &T,
+        writer: &mut W,
+        ctx: Context,
+    ) -> io::Result<()>
+    where
+        T: Describe,
+    {
+        Self::describe_list_internal(data, &[], Some(footer), None, writer, ctx)?;
         writeln!(writer)
     }
 
+    pub fn describe_list_grouped<W: io::Write, T>(
+        data: &[T],
+        group_by: &str,
+        writer: &mut W,
+        ctx: Context,
+    ) -> io::Result<()>
+    where
+        T: Describe,
+    {
+        if ctx.strict_headers {
+            Self::check_headers::<T>(&[group_by.to_string()])?;
+        }
+        let headers = T::default_headers()
+            .into_iter()
+            .filter(|header| header != group_by)
+            .collect::<Vec<_>>();
+        let (header_names, rows, col_widths) = Self::compute_table(data, &headers, &ctx);
+
+        // Groups keep the order in which their value first appears
+        let mut groups: Vec<(String, Vec<Vec<String>>)> = Vec::new();
+        for (item, row) in data.iter().zip(rows) {
+            let value = item.to_field(group_by);
+            match groups.iter_mut().find(|(group, _)| *group == value) {
+                Some((_, rows)) => rows.push(row),
+                None => groups.push((value, vec![row])),
+            }
+        }
+
+        if groups.is_empty() {
+            writeln!(writer, "{}", EMPTY_LIST)?;
+        }
+        for (idx, (value, rows)) in groups.into_iter().enumerate() {
+            if idx > 0 {
+                writeln!(writer)?;
+            }
+            writeln!(writer, "{:<offset$}{}:", "", value, offset = ctx.offset)?;
+            Self::write_header(writer, &header_names, &col_widths, &ctx)?;
+            for (row_idx, row) in rows.into_iter().enumerate() {
+                Self::write_row(writer, &row, &col_widths, &ctx, ctx.stripe(row_idx))?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
     fn describe_list_internal<W: io::Write, T>(
         data: &[T],
         headers: &[String],
+        footer: Option<&T>,
+        term_width: Option<usize>,
         writer: &mut W,
         ctx: Context,
     ) -> io::Result<()>
     where
         T: Describe,
     {
+        if ctx.strict_headers {
+            Self::check_headers::<T>(headers)?;
+        }
+        let (header_names, mut rows, mut col_widths) = Self::compute_table(data, headers, &ctx);
+
+        // The footer is laid out with the other rows so it shares their widths
+        let footer = footer.map(|footer| {
+            let default_headers = T::default_headers();
+            let headers = if headers.is_empty() {
+                default_headers.as_slice()
+            } else {
+                headers
+            };
+            let row =
+                Self::compute_rows(std::slice::from_ref(footer), headers, &ctx.null_placeholder)
+                    .remove(0);
+            for (idx, cell) in row.iter().enumerate() {
+                col_widths[idx] = col_widths[idx].max(Self::compute_cell_size(cell));
+            }
+            row
+        });
+
+        if let Some(term_width) = term_width {
+            let min_widths = header_names
+                .iter()
+                .map(|x| display_width(x))
+                .collect::<Vec<_>>();
+            // Every cell is preceded by the offset, columns are separated by a space
+            let spacing = (ctx.offset + 1) * col_widths.len();
+            Self::fit_widths(
+                &mut col_widths,
+                &min_widths,
+                term_width.saturating_sub(spacing) + 1,
+            );
+            for row in rows.iter_mut() {
+                for (idx, cell) in row.iter_mut().enumerate() {
+                    if Self::compute_cell_size(cell) > col_widths[idx] {
+                        *cell = cell
+                            .split('\n')
+                            .map(|line| truncate(line, col_widths[idx]))
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                    }
+                }
+            }
+        }
+
+        // Print header
+        Self::write_header(writer, &header_names, &col_widths, &ctx)?;
+
+        // Print rows
+        if rows.is_empty() {
+            writeln!(writer, "{}", EMPTY_LIST)?;
+        }
+        for (row_idx, row) in rows.into_iter().enumerate() {
+            Self::write_row(writer, &row, &col_widths, &ctx, ctx.stripe(row_idx))?;
+        }
+
+        if let Some(footer) = footer {
+            writeln!(writer)?;
+            let separator = col_widths
+                .iter()
+                .map(|width| format!("{:<offset$}{}", "", "-".repeat(*width), offset = ctx.offset))
+                .collect::<Vec<_>>()
+                .join(" ");
+            write!(writer, "{}", separator)?;
+            Self::write_row(writer, &footer, &col_widths, &ctx, (String::new(), ""))?;
+        }
+
+        Ok(())
+    }
+
+    // Write a table of cells already rendered, for tables that are not made of `Describe` items
+    fn describe_rows<W: io::Write>(
+        header_names: &[String],
+        rows: &[Vec<String>],
+        writer: &mut W,
+        ctx: &Context,
+    ) -> io::Result<()> {
+        let mut col_widths = header_names
+            .iter()
+            .map(|x| display_width(x))
+            .collect::<Vec<_>>();
+        for row in rows.iter() {
+            for (idx, cell) in row.iter().enumerate() {
+                col_widths[idx] = col_widths[idx].max(Self::compute_cell_size(cell))
+            }
+        }
+
+        Self::write_header(writer, header_names, &col_widths, ctx)?;
+        if rows.is_empty() {
+            writeln!(writer, "{}", EMPTY_LIST)?;
+        }
+        for (row_idx, row) in rows.iter().enumerate() {
+            Self::write_row(writer, row, &col_widths, ctx, ctx.stripe(row_idx))?;
+        }
+        writeln!(writer)
+    }
+
+    fn write_header<W: io::Write>(
+        writer: &mut W,
+        header_names: &[String],
+        col_widths: &[usize],
+        ctx: &Context,
+    ) -> io::Result<()> {
+        for (idx, cell) in header_names.iter().enumerate() {
+            if idx > 0 {
+                write!(writer, " ")?;
+            }
+
+            let space = if idx + 1 != header_names.len() {
+                format!(
+                    "{:width$}",
+                    "",
+                    width = col_widths[idx] - display_width(cell)
+                )
+            } else {
+                String::new()
+            };
+
+            write!(
+                writer,
+                "{:<offset$}{}{}",
+                "",
+                ctx.style(cell, BOLD),
+                space,
+                offset = ctx.offset
+            )?;
+        }
+        Ok(())
+    }
+
+    // Write a row on its own lines, multi-line cells are written on continuation lines
+    // while other columns are left blank. The stripe is started again after every cell
+    // since a colored cell ends with a reset
+    fn write_row<W: io::Write>(
+        writer: &mut W,
+        row: &[String],
+        col_widths: &[usize],
+        ctx: &Context,
+        (stripe_start, stripe_end): (String, &str),
+    ) -> io::Result<()> {
+        let cells = row
+            .iter()
+            .map(|cell| cell.split('\n').collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let height = cells.iter().map(|lines| lines.len()).max().unwrap_or(1);
+
+        for line_idx in 0..height {
+            let mut line = String::new();
+            for (idx, lines) in cells.iter().enumerate() {
+                if idx > 0 {
+                    line.push(' ');
+                }
+                let cell = lines.get(line_idx).copied().unwrap_or_default();
+                let space = if idx + 1 != cells.len() {
+                    format!(
+                        "{}{:width$}",
+                        stripe_start,
+                        "",
+                        width = col_widths[idx].saturating_sub(display_width(cell))
+                    )
+                } else {
+                    String::new()
+                };
+                line.push_str(&format!(
+                    "{:<offset$}{}{}",
+                    "",
+                    cell,
+                    space,
+                    offset = ctx.offset
+                ));
+            }
+            let line = if line_idx > 0 { line.trim_end() } else { &line };
+            writeln!(writer)?;
+            write!(writer, "{}{}{}", stripe_start, line, stripe_end)?;
+        }
+
+        Ok(())
+    }
+
+    // Reject the requested headers that are not a field of `T`, listing the valid ones
+    fn check_headers<T: Describe>(headers: &[String]) -> io::Result<()> {
+        validate_headers::<T>(headers).map_err(|unknown| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unknown headers: {}, valid headers are: {}",
+                    unknown.join(", "),
+                    T::headers().join(", ")
+                ),
+            )
+        })
+    }
+
+    // Compute the header labels, the rows and the width of every column
+    fn compute_table<T: Describe>(
+        data: &[T],
+        headers: &[String],
+        ctx: &Context,
+    ) -> (Vec<String>, Vec<Vec<String>>, Vec<usize>) {
         // Compute headers to display
         let default_headers: Vec<String> =
             T::default_headers().iter().map(|x| x.to_string()).collect();
@@ -718,100 +2925,209 @@ impl Describer {
             default_headers.as_slice()
         } else {
             headers
-        };
-
-        // Compute rows
-        let rows = data
-            .iter()
-            .map(|row| {
-                headers
-                    .iter()
-                    .map(|x| {
-                        let val = x.as_str();
-                        row.to_field(val)
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>();
+        };
+
+        // Compute rows
+        let rows = Self::compute_rows(data, headers, &ctx.null_placeholder);
 
         let header_names = headers
             .iter()
-            .map(|header| match T::header_name(header) {
-                None => header.to_string().to_case(Case::UpperSnake),
-                Some(header) => header,
-            })
+            .map(|header| T::label_with_case(header, ctx.header_case))
             .collect::<Vec<_>>();
 
         // Compute columns width
         let mut col_widths = header_names
             .iter()
-            .map(|header| header.len())
+            .zip(headers)
+            .map(|(name, header)| display_width(name).max(T::min_width(header)))
             .collect::<Vec<_>>();
         for row in rows.iter() {
             for (idx, cell) in row.iter().enumerate() {
-                col_widths[idx] = col_widths[idx].max(Self::compute_string_size(cell))
+                col_widths[idx] = col_widths[idx].max(Self::compute_cell_size(cell))
             }
         }
 
-        let header_len = header_names.len();
-        // Print header
-        for (idx, cell) in header_names.into_iter().enumerate() {
-            if idx > 0 {
-                write!(writer, " ")?;
+        (header_names, rows, col_widths)
+    }
+
+    pub fn describe_grid<W: io::Write, T>(
+        data: &[T],
+        writer: &mut W,
+        ctx: Context,
+    ) -> io::Result<()>
+    where
+        T: Describe,
+    {
+        let (header_names, rows, mut col_widths) = Self::compute_table(data, &[], &ctx);
+
+        // The empty message spans every column, widen the last one to fit it
+        let inner_width = col_widths.iter().sum::<usize>() + 3 * col_widths.len().saturating_sub(1);
+        if rows.is_empty() && inner_width < EMPTY_LIST.len() {
+            if let Some(width) = col_widths.last_mut() {
+                *width += EMPTY_LIST.len() - inner_width;
             }
+        }
 
-            let space = if idx + 1 != header_len {
-                format!("{:width$}", "", width = col_widths[idx] - cell.len())
-            } else {
-                format!("")
-            };
+        let border = col_widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>()
+            .join("+");
+        let border = format!("+{}+", border);
+        // The whole grid is moved right by the offset
+        let margin = " ".repeat(ctx.offset);
 
-            write!(
+        writeln!(writer, "{}{}", margin, border)?;
+        Self::write_grid_line(&header_names, &col_widths, &margin, writer)?;
+        writeln!(writer, "{}{}", margin, border)?;
+
+        if rows.is_empty() {
+            let inner_width = border.len().saturating_sub(4).max(EMPTY_LIST.len());
+            writeln!(
                 writer,
-                "{:<offset$}{}{}",
-                "",
-                cell.as_str(),
-                space,
-                offset = ctx.offset
+                "{}| {:<width$} |",
+                margin,
+                EMPTY_LIST,
+                width = inner_width
             )?;
+            return writeln!(writer, "{}+{}+", margin, "-".repeat(inner_width + 2));
         }
 
-        // Print rows
-        if rows.is_empty() {
-            writeln!(writer, "Empty list")?;
-        }
         for row in rows {
-            writeln!(writer)?;
-            for (idx, cell) in row.into_iter().enumerate() {
-                if idx > 0 {
-                    writer.write_fmt(format_args!(" "))?;
-                }
-                let space = if idx + 1 != header_len {
-                    format!(
-                        "{:width$}",
-                        "",
-                        width = col_widths[idx] - Self::compute_string_size(&cell)
-                    )
-                } else {
-                    format!("")
-                };
-                writer.write_fmt(format_args!(
-                    "{:<offset$}{}{}",
-                    "",
-                    cell,
-                    space,
-                    offset = ctx.offset
-                ))?;
+            // Multi-line cells are written on continuation lines, other columns are left blank
+            let cells = row
+                .iter()
+                .map(|cell| cell.split('\n').collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let height = cells.iter().map(|lines| lines.len()).max().unwrap_or(1);
+            for line_idx in 0..height {
+                let line = cells
+                    .iter()
+                    .map(|lines| lines.get(line_idx).copied().unwrap_or_default())
+                    .collect::<Vec<_>>();
+                Self::write_grid_line(&line, &col_widths, &margin, writer)?;
+            }
+            writeln!(writer, "{}{}", margin, border)?;
+        }
+        Ok(())
+    }
+
+    fn write_grid_line<W: io::Write, S: AsRef<str>>(
+        cells: &[S],
+        col_widths: &[usize],
+        margin: &str,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        write!(writer, "{}", margin)?;
+        for (cell, width) in cells.iter().zip(col_widths) {
+            let cell = cell.as_ref();
+            let space = width - display_width(cell);
+            write!(writer, "| {}{:space$} ", cell, "", space = space)?;
+        }
+        writeln!(writer, "|")
+    }
+
+    // Shrink the widest columns one char at a time until the total fits the budget
+    // A column never gets narrower than its header
+    fn fit_widths(col_widths: &mut [usize], min_widths: &[usize], budget: usize) {
+        while col_widths.iter().sum::<usize>() > budget {
+            let widest = col_widths
+                .iter()
+                .enumerate()
+                .filter(|(idx, width)| **width > min_widths[*idx])
+                .max_by_key(|(_, width)| **width)
+                .map(|(idx, _)| idx);
+            match widest {
+                Some(idx) => col_widths[idx] -= 1,
+                None => break,
             }
         }
+    }
+
+    fn compute_rows<T: Describe>(
+        data: &[T],
+        headers: &[String],
+        null_placeholder: &str,
+    ) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|row| {
+                headers
+                    .iter()
+                    .map(|x| row.to_field_with_null(x, null_placeholder))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    }
+
+    // Width of the widest line of a cell
+    fn compute_cell_size(cell: &str) -> usize {
+        cell.split('\n')
+            .map(display_width)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// Write a table row by row with fixed column widths, so rows never have to be collected.
+///
+/// Columns are widened to fit their label. A cell wider than its column is written in full
+/// and pushes the following cells of its row.
+pub struct TableWriter<W: io::Write> {
+    writer: W,
+    headers: Vec<String>,
+    col_widths: Vec<usize>,
+    ctx: Context,
+    rows: usize,
+}
+
+impl<W: io::Write> TableWriter<W> {
+    /// Write the header of a table of `T`
+    pub fn new<T: Describe>(mut writer: W, col_widths: &[usize], ctx: Context) -> io::Result<Self> {
+        let headers = T::default_headers();
+        let header_names = headers
+            .iter()
+            .map(|header| T::label_with_case(header, ctx.header_case))
+            .collect::<Vec<_>>();
+        let col_widths = header_names
+            .iter()
+            .zip(&headers)
+            .enumerate()
+            .map(|(idx, (name, header))| {
+                let width = col_widths.get(idx).copied().unwrap_or_default();
+                width.max(display_width(name)).max(T::min_width(header))
+            })
+            .collect::<Vec<_>>();
+        Describer::write_header(&mut writer, &header_names, &col_widths, &ctx)?;
+
+        Ok(Self {
+            writer,
+            headers,
+            col_widths,
+            ctx,
+            rows: 0,
+        })
+    }
 
+    /// Write the fields of `item` as the next row
+    pub fn write_row<T: Describe>(&mut self, item: &T) -> io::Result<()> {
+        let row = self
+            .headers
+            .iter()
+            .map(|header| item.to_field_with_null(header, &self.ctx.null_placeholder))
+            .collect::<Vec<_>>();
+        let stripe = self.ctx.stripe(self.rows);
+        Describer::write_row(&mut self.writer, &row, &self.col_widths, &self.ctx, stripe)?;
+        self.rows += 1;
         Ok(())
     }
 
-    fn compute_string_size(str: &str) -> usize {
-        String::from_utf8(strip_ansi_escapes::strip(str).unwrap())
-            .unwrap_or_else(|_| str.to_string())
-            .len()
+    /// End the table like `table_describe` does and give the writer back
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.rows == 0 {
+            writeln!(self.writer, "{}", EMPTY_LIST)?;
+        }
+        writeln!(self.writer)?;
+        Ok(self.writer)
     }
 }
 
@@ -822,10 +3138,97 @@ pub fn object_describe_to_string<T: Describe>(object: &T) -> io::Result<String>
     Ok(string)
 }
 
+/// Describe the object without the leading line break, to embed it in another output
+pub fn object_describe_compact_to_string<T: Describe>(object: &T) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_object_compact(object, &mut vec, Context::default())?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+pub fn object_describe_with_options<T: Describe>(
+    object: &T,
+    opts: &DescribeOptions,
+) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_object(object, &mut vec, opts.context())?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
 pub fn object_describe<W: io::Write, T: Describe>(object: &T, writer: &mut W) -> io::Result<()> {
     Describer::describe_object(object, writer, Context::default())
 }
 
+/// Export every field as a flat JSON object, keyed by their machine key
+#[cfg(feature = "serde")]
+pub fn object_describe_json_to_string<T: Describe>(object: &T) -> io::Result<String> {
+    let map = object
+        .describe_pairs()
+        .into_iter()
+        .map(|(header, value)| {
+            let key = T::machine_key(&header).unwrap_or(header);
+            (key, serde_json::Value::String(value))
+        })
+        .collect::<serde_json::Map<_, _>>();
+    Ok(serde_json::to_string(&map)?)
+}
+
+pub fn object_describe_oneline_to_string<T: Describe>(object: &T) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_oneline(object, &mut vec)?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+pub fn object_describe_ledger_to_string<T: Describe>(
+    object: &T,
+    width: usize,
+) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_ledger(object, width, &mut vec)?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+/// Return the cells of every row with its index, letting callers style rows themselves
+pub fn table_rows<T: Describe>(data: &[T], headers: &[String]) -> Vec<(usize, Vec<String>)> {
+    let default_headers = T::default_headers();
+    let headers = if headers.is_empty() {
+        default_headers.as_slice()
+    } else {
+        headers
+    };
+    Describer::compute_rows(data, headers, NULL_PLACEHOLDER)
+        .into_iter()
+        .enumerate()
+        .collect()
+}
+
+pub fn label_of<T: Describe>(field: &str) -> String {
+    T::label_for(field)
+}
+
+/// Every header a table of `T` accepts, e.g. to list valid `--columns` values
+pub fn headers_of<T: Describe>() -> Vec<String> {
+    T::headers()
+}
+
+/// Check user supplied headers before rendering, returning the unknown ones
+pub fn validate_headers<T: Describe>(headers: &[String]) -> Result<(), Vec<String>> {
+    let known = T::headers();
+    let unknown = headers
+        .iter()
+        .filter(|header| !known.contains(header))
+        .cloned()
+        .collect::<Vec<_>>();
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(unknown)
+    }
+}
+
 pub fn table_describe_to_string<T: Describe>(data: &[T]) -> io::Result<String> {
     let mut vec = Vec::with_capacity(128);
     Describer::describe_list(data, &mut vec, Context::default())?;
@@ -833,6 +3236,97 @@ pub fn table_describe_to_string<T: Describe>(data: &[T]) -> io::Result<String> {
     Ok(string)
 }
 
+pub fn table_describe_with_options<T: Describe>(
+    data: &[T],
+    opts: &DescribeOptions,
+) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_list(data, &mut vec, opts.context())?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+/// Transposed table of a single item, one row per field labelled like the table headers
+pub fn table_describe_vertical_to_string<T: Describe>(item: &T) -> io::Result<String> {
+    table_describe_vertical_with_options(item, &DescribeOptions::default())
+}
+
+pub fn table_describe_vertical_with_options<T: Describe>(
+    item: &T,
+    options: &DescribeOptions,
+) -> io::Result<String> {
+    let ctx = options.context();
+    let header_names = ["field", "value"].map(|x| ctx.header_case.apply(x));
+    let rows = T::default_headers()
+        .into_iter()
+        .map(|header| {
+            vec![
+                T::label_with_case(&header, ctx.header_case),
+                item.to_field_with_null(&header, &ctx.null_placeholder),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_rows(&header_names, &rows, &mut vec, &ctx)?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+/// Count the occurrences of every value, most frequent first
+pub fn table_describe_counts_to_string<T: Describe>(data: &[T]) -> io::Result<String> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for value in data.iter().map(|x| x.to_field("")) {
+        match counts.iter_mut().find(|(x, _)| *x == value) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+    // Stable sort, ties keep their first appearance order
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    let ctx = Context::default();
+    let header_names = ["value", "count"].map(|x| ctx.header_case.apply(x));
+    let rows = counts
+        .into_iter()
+        .map(|(value, count)| vec![value, count.to_string()])
+        .collect::<Vec<_>>();
+
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_rows(&header_names, &rows, &mut vec, &ctx)?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+/// Render the table within `term_width` columns, truncating the widest columns with `…`
+pub fn table_describe_fit_to_string<T: Describe>(
+    data: &[T],
+    term_width: usize,
+) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_list_fit(data, term_width, &mut vec, Context::default())?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+/// Render the table with ASCII borders around every cell
+pub fn table_describe_grid_to_string<T: Describe>(data: &[T]) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_grid(data, &mut vec, Context::default())?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+pub fn table_describe_limited_to_string<T: Describe>(
+    data: &[T],
+    max_rows: usize,
+) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_list_limited(data, max_rows, &mut vec, Context::default())?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
 pub fn table_describe_with_header_to_string<T: Describe>(
     data: &[T],
     headers: &[String],
@@ -843,6 +3337,41 @@ pub fn table_describe_with_header_to_string<T: Describe>(
     Ok(string)
 }
 
+/// Like `table_describe_with_header_to_string`, but unknown headers fail with an `InvalidInput`
+/// error listing the valid ones, e.g. to validate a `--columns` flag
+pub fn table_describe_strict_to_string<T: Describe>(
+    data: &[T],
+    headers: &[String],
+) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    let ctx = Context::default().with_strict_headers(true);
+    Describer::describe_list_with_header(data, headers, &mut vec, ctx)?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+/// Render the table followed by a separator line and the `footer` row, e.g. a totals record
+pub fn table_describe_with_footer_to_string<T: Describe>(
+    data: &[T],
+    footer: &T,
+) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_list_with_footer(data, footer, &mut vec, Context::default())?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
+/// Render one sub-table per distinct `group_by` value, in order of first appearance
+pub fn table_describe_grouped_to_string<T: Describe>(
+    data: &[T],
+    group_by: &str,
+) -> io::Result<String> {
+    let mut vec = Vec::with_capacity(128);
+    Describer::describe_list_grouped(data, group_by, &mut vec, Context::default())?;
+    let string = String::from_utf8(vec).unwrap();
+    Ok(string)
+}
+
 pub fn table_describe<W: io::Write, T: Describe>(
     data: &[T],
     headers: &[String],
@@ -851,6 +3380,74 @@ pub fn table_describe<W: io::Write, T: Describe>(
     Describer::describe_list_with_header(data, headers, writer, Context::default())
 }
 
+// Number of rows used to estimate the column widths of a streamed table
+const STREAMING_SAMPLE: usize = 100;
+
+/// Stream a table from an iterator, with the given column widths or widths estimated
+/// on the first rows, see `TableWriter`
+pub fn table_describe_streaming<W, T, I>(
+    items: I,
+    col_widths: Option<&[usize]>,
+    writer: &mut W,
+) -> io::Result<()>
+where
+    W: io::Write,
+    T: Describe,
+    I: IntoIterator<Item = T>,
+{
+    let mut items = items.into_iter();
+    let (sample, col_widths) = match col_widths {
+        Some(col_widths) => (Vec::new(), col_widths.to_vec()),
+        None => {
+            let sample = items.by_ref().take(STREAMING_SAMPLE).collect::<Vec<_>>();
+            let (_, _, col_widths) = Describer::compute_table(&sample, &[], &Context::default());
+            (sample, col_widths)
+        }
+    };
+
+    let mut table = TableWriter::new::<T>(writer, &col_widths, Context::default())?;
+    for item in sample.iter() {
+        table.write_row(item)?;
+    }
+    for item in items {
+        table.write_row(&item)?;
+    }
+    table.finish()?;
+    Ok(())
+}
+
+/// Async version of `object_describe`, the description is rendered in memory then written
+#[cfg(feature = "tokio")]
+pub async fn object_describe_async<W, T>(object: &T, writer: &mut W) -> io::Result<()>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+    T: Describe,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let mut vec = Vec::with_capacity(128);
+    object_describe(object, &mut vec)?;
+    writer.write_all(&vec).await
+}
+
+/// Async version of `table_describe`, the table is rendered in memory then written
+#[cfg(feature = "tokio")]
+pub async fn table_describe_async<W, T>(
+    data: &[T],
+    headers: &[String],
+    writer: &mut W,
+) -> io::Result<()>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+    T: Describe,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let mut vec = Vec::with_capacity(128);
+    table_describe(data, headers, &mut vec)?;
+    writer.write_all(&vec).await
+}
+
 #[doc(hidden)]
 macro_rules! describe_macro_to_string {
     (
@@ -869,7 +3466,143 @@ describe_macro_to_string!(i32);
 describe_macro_to_string!(i64);
 describe_macro_to_string!(u32);
 describe_macro_to_string!(u64);
+describe_macro_to_string!(u8);
 describe_macro_to_string!(u16);
+describe_macro_to_string!(i8);
 describe_macro_to_string!(i16);
 describe_macro_to_string!(usize);
+describe_macro_to_string!(f32);
+describe_macro_to_string!(f64);
 describe_macro_to_string!(bool);
+describe_macro_to_string!(IpAddr);
+describe_macro_to_string!(Ipv4Addr);
+describe_macro_to_string!(Ipv6Addr);
+describe_macro_to_string!(SocketAddr);
+describe_macro_to_string!(SocketAddrV4);
+describe_macro_to_string!(SocketAddrV6);
+
+#[doc(hidden)]
+macro_rules! describe_macro_non_zero {
+    (
+        $($t: ty),*
+    ) => {
+        $(
+            impl Describe for $t {
+                fn to_field(&self, _: &str) -> String {
+                    self.get().to_string()
+                }
+            }
+        )*
+    };
+}
+
+describe_macro_non_zero!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize
+);
+
+#[doc(hidden)]
+macro_rules! describe_macro_tuple {
+    (
+        $($t: ident)+ ; $last: ident
+    ) => {
+        #[allow(non_snake_case)]
+        impl<$($t: Describe),+, $last: Describe> Describe for ($($t,)+ $last) {
+            fn to_field(&self, _: &str) -> String {
+                let ($($t,)+ $last) = self;
+                vec![$($t.to_field("")),+, $last.to_field("")].join(DEFAULT_SEPARATOR)
+            }
+
+            // A trailing collection is described as a sub-list under the other values
+            fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+                let ($($t,)+ $last) = self;
+                if $last::is_collection() {
+                    let prefix = vec![$($t.to_field("")),+].join(", ");
+                    ctx.write_value(writer, prefix)?;
+                    $last.describe(writer, ctx.indent(1, 0))
+                } else {
+                    let mut ctx = ctx;
+                    $(
+                        $t.describe(writer, ctx.array())?;
+                        ctx.leading_newline = true;
+                    )+
+                    $last.describe(writer, ctx.array())
+                }
+            }
+        }
+    };
+}
+
+describe_macro_tuple!(A; B);
+describe_macro_tuple!(A B; C);
+describe_macro_tuple!(A B C; D);
+describe_macro_tuple!(A B C D; E);
+describe_macro_tuple!(A B C D E; F);
+describe_macro_tuple!(A B C D E F; G);
+describe_macro_tuple!(A B C D E F G; H);
+describe_macro_tuple!(A B C D E F G H; I);
+describe_macro_tuple!(A B C D E F G H I; J);
+describe_macro_tuple!(A B C D E F G H I J; K);
+describe_macro_tuple!(A B C D E F G H I J K; L);
+
+#[doc(hidden)]
+macro_rules! describe_macro_deref {
+    (
+        $t: ty
+    ) => {
+        impl<T: Describe + ?Sized> Describe for $t {
+            fn to_field(&self, field_name: &str) -> String {
+                (**self).to_field(field_name)
+            }
+
+            fn to_field_with_null(&self, field_name: &str, null_placeholder: &str) -> String {
+                (**self).to_field_with_null(field_name, null_placeholder)
+            }
+
+            fn default_headers() -> Vec<String> {
+                T::default_headers()
+            }
+
+            fn headers() -> Vec<String> {
+                T::headers()
+            }
+
+            fn header_name(header: &str) -> Option<String> {
+                T::header_name(header)
+            }
+
+            fn machine_key(header: &str) -> Option<String> {
+                T::machine_key(header)
+            }
+
+            fn min_width(header: &str) -> usize {
+                T::min_width(header)
+            }
+
+            fn struct_pad() -> usize {
+                T::struct_pad()
+            }
+
+            fn is_collection() -> bool {
+                T::is_collection()
+            }
+
+            fn describe<W: io::Write>(&self, writer: &mut W, ctx: Context) -> io::Result<()> {
+                (**self).describe(writer, ctx)
+            }
+        }
+    };
+}
+
+describe_macro_deref!(&T);
+describe_macro_deref!(Box<T>);
+describe_macro_deref!(Rc<T>);
+describe_macro_deref!(Arc<T>);
@@ -1,6 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
-use descriptor::{object_describe_to_string, Descriptor};
+use descriptor::{
+    object_describe_ledger_to_string, object_describe_oneline_to_string, object_describe_to_string,
+    object_describe_with_options, DescribeOptions, Descriptor,
+};
 
 pub fn no_color(str: String) -> String {
     String::from_utf8(strip_ansi_escapes::strip(str).unwrap()).unwrap()
@@ -180,6 +183,7 @@ fn test_into_struct_level() {
         }
     }
 
+    #[allow(clippy::from_over_into)]
     impl Into<TestIntoReceiver> for &TestInto {
         fn into(self) -> TestIntoReceiver {
             TestIntoReceiver {
@@ -389,3 +393,2462 @@ Map:
         no_color(description)
     );
 }
+
+#[test]
+fn test_tuple_struct() {
+    #[derive(Descriptor)]
+    struct Point(i32, i32);
+
+    let description = object_describe_to_string(&Point(1, 2)).unwrap();
+    assert_eq!(
+        r#"
+Field 0: 1
+Field 1: 2
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_describe_enum_with_data() {
+    #[derive(Descriptor)]
+    struct Job {
+        name: String,
+        status: Status,
+    }
+
+    #[derive(Descriptor)]
+    enum Status {
+        Failed(String),
+        Active { since: u32 },
+    }
+
+    let description = object_describe_to_string(&Job {
+        name: "build".to_string(),
+        status: Status::Failed("timeout".to_string()),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Name:   build
+Status:
+  Failed: timeout
+"#,
+        no_color(description)
+    );
+
+    let description = object_describe_to_string(&Job {
+        name: "deploy".to_string(),
+        status: Status::Active { since: 10 },
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Name:   deploy
+Status:
+  Active:
+    Since: 10
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_oneline() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        age: u32,
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        town: String,
+    }
+
+    let line = object_describe_oneline_to_string(&User {
+        name: "Adrien Carreira".to_string(),
+        age: 32,
+        address: Address {
+            town: "NY".to_string(),
+        },
+    })
+    .unwrap();
+    assert_eq!(r#"name="Adrien Carreira" age=32 address.town=NY"#, line);
+}
+
+#[test]
+fn test_generic_struct() {
+    #[derive(Descriptor)]
+    struct Wrapper<T> {
+        inner: T,
+    }
+
+    let description = object_describe_to_string(&Wrapper {
+        inner: "value".to_string(),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Inner: value
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_btree_map() {
+    #[derive(Descriptor)]
+    struct B {
+        map: BTreeMap<String, String>,
+    }
+
+    let mut map = BTreeMap::new();
+    map.insert("town".to_string(), "NY".to_string());
+    map.insert("age".to_string(), "32".to_string());
+    map.insert("name".to_string(), "Adrien".to_string());
+
+    let description = object_describe_to_string(&B { map }).unwrap();
+    assert_eq!(
+        r#"
+Map:
+  age:  32
+  name: Adrien
+  town: NY
+"#,
+        no_color(description)
+    );
+
+    let description = object_describe_to_string(&B {
+        map: BTreeMap::new(),
+    })
+    .unwrap();
+    assert_eq!("\nMap: ~\n", no_color(description));
+}
+
+#[test]
+fn test_sets() {
+    #[derive(Descriptor)]
+    struct Foo {
+        hash_set: HashSet<String>,
+        btree_set: BTreeSet<u32>,
+        empty: BTreeSet<u32>,
+    }
+
+    let description = object_describe_to_string(&Foo {
+        hash_set: vec!["b".to_string(), "c".to_string(), "a".to_string()]
+            .into_iter()
+            .collect(),
+        btree_set: vec![3, 1, 2].into_iter().collect(),
+        empty: BTreeSet::new(),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Hash Set:
+- a
+- b
+- c
+Btree Set:
+- 1
+- 2
+- 3
+Empty:     ~
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_ledger() {
+    #[derive(Descriptor)]
+    struct Receipt {
+        subtotal: String,
+        tax: String,
+        total_due: String,
+    }
+
+    let ledger = object_describe_ledger_to_string(
+        &Receipt {
+            subtotal: "42.00".to_string(),
+            tax: "8.40".to_string(),
+            total_due: "50.40".to_string(),
+        },
+        20,
+    )
+    .unwrap();
+    assert_eq!(
+        r#"Subtotal       42.00
+Tax             8.40
+Total Due      50.40
+"#,
+        ledger
+    );
+}
+
+#[test]
+fn test_ledger_rename_header() {
+    #[derive(Descriptor)]
+    struct Receipt {
+        subtotal: String,
+        #[descriptor(rename_header = "VAT")]
+        tax: String,
+    }
+
+    let ledger = object_describe_ledger_to_string(
+        &Receipt {
+            subtotal: "42.00".to_string(),
+            tax: "8.40".to_string(),
+        },
+        20,
+    )
+    .unwrap();
+    assert_eq!(
+        r#"Subtotal       42.00
+VAT             8.40
+"#,
+        ledger
+    );
+}
+
+#[test]
+fn test_tuple_field() {
+    #[derive(Descriptor)]
+    struct Foo {
+        pair: (String, i32),
+    }
+
+    let description = object_describe_to_string(&Foo {
+        pair: ("answer".to_string(), 42),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Pair:
+- answer
+- 42
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_exit_status() {
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::ExitStatus;
+
+    #[derive(Descriptor)]
+    struct Run {
+        success: ExitStatus,
+        failure: ExitStatus,
+        killed: ExitStatus,
+    }
+
+    let description = object_describe_to_string(&Run {
+        success: ExitStatus::from_raw(0),
+        failure: ExitStatus::from_raw(2 << 8),
+        killed: ExitStatus::from_raw(9),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Success: exit code 0
+Failure: exit code 2
+Killed:  signal 9
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_box() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct BoxedUser {
+        name: String,
+        address: Box<Address>,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        street: String,
+        town: String,
+    }
+
+    let address = || Address {
+        street: "Main street".to_string(),
+        town: "NY".to_string(),
+    };
+
+    let description = object_describe_to_string(&User {
+        name: "Adrien".to_string(),
+        address: address(),
+    })
+    .unwrap();
+    let boxed = object_describe_to_string(&BoxedUser {
+        name: "Adrien".to_string(),
+        address: Box::new(address()),
+    })
+    .unwrap();
+    assert_eq!(description, boxed);
+}
+
+#[test]
+fn test_cow() {
+    use std::borrow::Cow;
+
+    #[derive(Descriptor)]
+    struct Token<'a> {
+        borrowed: Cow<'a, str>,
+        owned: Cow<'a, str>,
+    }
+
+    let source = "borrowed value";
+    let description = object_describe_to_string(&Token {
+        borrowed: Cow::Borrowed(source),
+        owned: Cow::Owned("owned value".to_string()),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Borrowed: borrowed value
+Owned:    owned value
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_path() {
+    use std::path::{Path, PathBuf};
+
+    #[derive(Descriptor)]
+    struct Settings<'a> {
+        config: PathBuf,
+        cache: &'a Path,
+    }
+
+    let description = object_describe_to_string(&Settings {
+        config: PathBuf::from("/etc/app/config.toml"),
+        cache: Path::new("/var/cache/app"),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Config: /etc/app/config.toml
+Cache:  /var/cache/app
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_table_description_with_option() {
+    #[derive(Descriptor)]
+    struct Foo {
+        #[descriptor(output_table)]
+        history: Vec<InnerFoo>,
+    }
+
+    #[derive(Descriptor)]
+    struct InnerFoo {
+        state: String,
+        comment: Option<String>,
+        value: String,
+    }
+
+    let description = object_describe_to_string(&Foo {
+        history: vec![
+            InnerFoo {
+                state: "done".to_string(),
+                comment: Some("all good".to_string()),
+                value: "t".to_string(),
+            },
+            InnerFoo {
+                state: "failed".to_string(),
+                comment: None,
+                value: "f".to_string(),
+            },
+        ],
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+History:
+  STATE    COMMENT    VALUE
+  done     all good   t
+  failed   ~          f
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_rename() {
+    #[derive(Descriptor)]
+    struct Server {
+        name: String,
+        #[descriptor(rename = "IP Address")]
+        ip: String,
+    }
+
+    let server = Server {
+        name: "web".to_string(),
+        ip: "10.0.0.1".to_string(),
+    };
+    let description = object_describe_to_string(&server).unwrap();
+    assert_eq!(
+        r#"
+Name:       web
+IP Address: 10.0.0.1
+"#,
+        no_color(description)
+    );
+    assert_eq!("10.0.0.1", descriptor::Describe::to_field(&server, "ip"));
+}
+
+#[test]
+fn test_duration_between() {
+    use std::time::{Duration, SystemTime};
+
+    #[derive(Descriptor)]
+    #[descriptor(duration_between(name = "elapsed", start = "started_at", end = "ended_at"))]
+    struct Job {
+        name: String,
+        #[descriptor(skip)]
+        started_at: SystemTime,
+        #[descriptor(skip)]
+        ended_at: Option<SystemTime>,
+    }
+
+    let started_at = SystemTime::UNIX_EPOCH;
+    let description = object_describe_to_string(&Job {
+        name: "build".to_string(),
+        started_at,
+        ended_at: Some(started_at + Duration::from_secs(2 * 3600 + 3 * 60 + 10)),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Name:    build
+Elapsed: 2h 3m
+"#,
+        no_color(description)
+    );
+
+    let description = object_describe_to_string(&Job {
+        name: "running".to_string(),
+        started_at,
+        ended_at: None,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Name:    running
+Elapsed: ~
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_title_separator() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        age: u32,
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        town: String,
+    }
+
+    let description = object_describe_with_options(
+        &User {
+            name: "Adrien".to_string(),
+            age: 32,
+            address: Address {
+                town: "NY".to_string(),
+            },
+        },
+        &DescribeOptions {
+            title_separator: " =".to_string(),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        r#"
+Name =    Adrien
+Age =     32
+Address =
+  Town = NY
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_describe_enum_phantom_data() {
+    use std::marker::PhantomData;
+
+    #[derive(Descriptor)]
+    struct Machine<T> {
+        state: State<T>,
+    }
+
+    #[derive(Descriptor)]
+    enum State<T> {
+        Ready(PhantomData<T>),
+        Running { pid: u32, _marker: PhantomData<T> },
+    }
+
+    let description = object_describe_to_string(&Machine::<String> {
+        state: State::Ready(PhantomData),
+    })
+    .unwrap();
+    assert_eq!("\nState: Ready\n", no_color(description));
+
+    let description = object_describe_to_string(&Machine::<String> {
+        state: State::Running {
+            pid: 42,
+            _marker: PhantomData,
+        },
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+State:
+  Running:
+    Pid: 42
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_bullet() {
+    #[derive(Descriptor)]
+    struct Foo {
+        tags: Vec<String>,
+        list: Vec<FooChild>,
+    }
+
+    #[derive(Descriptor)]
+    struct FooChild {
+        first: String,
+        second: String,
+    }
+
+    let description = object_describe_with_options(
+        &Foo {
+            tags: vec!["a".to_string(), "b".to_string()],
+            list: vec![FooChild {
+                first: "1".to_string(),
+                second: "2".to_string(),
+            }],
+        },
+        &DescribeOptions {
+            bullet: "•".to_string(),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        r#"
+Tags:
+• a
+• b
+List:
+• First:  1
+  Second: 2
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_wide_bullet() {
+    #[derive(Descriptor)]
+    struct Foo {
+        list: Vec<FooChild>,
+    }
+
+    #[derive(Descriptor)]
+    struct FooChild {
+        first: String,
+        second: String,
+    }
+
+    let description = object_describe_with_options(
+        &Foo {
+            list: vec![FooChild {
+                first: "1".to_string(),
+                second: "2".to_string(),
+            }],
+        },
+        &DescribeOptions {
+            bullet: "👉".to_string(),
+            indent_width: 4,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        r#"
+List:
+👉  First:  1
+    Second: 2
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_indent_width() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        address: Address,
+        tags: Vec<String>,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        street: String,
+        town: String,
+    }
+
+    let description = object_describe_with_options(
+        &User {
+            name: "Adrien".to_string(),
+            address: Address {
+                street: "Main street".to_string(),
+                town: "NY".to_string(),
+            },
+            tags: vec!["admin".to_string()],
+        },
+        &DescribeOptions {
+            indent_width: 4,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        r#"
+Name:    Adrien
+Address:
+    Street: Main street
+    Town:   NY
+Tags:
+-   admin
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_tuple_with_trailing_list() {
+    #[derive(Descriptor)]
+    struct Report {
+        entry: (String, String, Vec<Detail>),
+    }
+
+    #[derive(Descriptor)]
+    struct Detail {
+        code: u32,
+        message: String,
+    }
+
+    let description = object_describe_to_string(&Report {
+        entry: (
+            "network".to_string(),
+            "timeout".to_string(),
+            vec![
+                Detail {
+                    code: 1,
+                    message: "first".to_string(),
+                },
+                Detail {
+                    code: 2,
+                    message: "second".to_string(),
+                },
+            ],
+        ),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Entry: network, timeout
+  - Code:    1
+    Message: first
+  - Code:    2
+    Message: second
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_date_format() {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[derive(Descriptor)]
+    struct Event {
+        created_at: DateTime<Utc>,
+        #[descriptor(date_format = "%Y-%m-%d")]
+        day: DateTime<Utc>,
+        #[descriptor(date_format = "%Y-%m-%d", resolve_option)]
+        updated_at: Option<DateTime<Utc>>,
+        #[descriptor(date_format = "%Y-%m-%d", resolve_option)]
+        deleted_at: Option<DateTime<Utc>>,
+    }
+
+    let date = Utc.with_ymd_and_hms(2021, 9, 15, 10, 30, 0).unwrap();
+    let description = object_describe_to_string(&Event {
+        created_at: date,
+        day: date,
+        updated_at: Some(date),
+        deleted_at: None,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Created At: 15-09-21 10:30:00
+Day:        2021-09-15
+Updated At: 2021-09-15
+Deleted At: ~
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_show_sign() {
+    #[derive(Descriptor)]
+    struct Delta {
+        #[descriptor(show_sign)]
+        added: i32,
+        #[descriptor(show_sign)]
+        removed: i32,
+        #[descriptor(show_sign)]
+        unchanged: i64,
+        #[descriptor(show_sign(signed_zero = false))]
+        moved: i64,
+        #[descriptor(show_sign, resolve_option)]
+        renamed: Option<i32>,
+    }
+
+    let description = object_describe_to_string(&Delta {
+        added: 5,
+        removed: -3,
+        unchanged: 0,
+        moved: 0,
+        renamed: None,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Added:     +5
+Removed:   -3
+Unchanged: +0
+Moved:     0
+Renamed:   ~
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_show_sign_float() {
+    #[derive(Descriptor)]
+    struct Delta {
+        #[descriptor(show_sign)]
+        gain: f64,
+        #[descriptor(show_sign)]
+        loss: f64,
+        #[descriptor(show_sign)]
+        zero: f64,
+        #[descriptor(show_sign)]
+        negative_zero: f64,
+    }
+
+    let description = object_describe_to_string(&Delta {
+        gain: 1.5,
+        loss: -2.5,
+        zero: 0.0,
+        negative_zero: -0.0,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Gain:          +1.5
+Loss:          -2.5
+Zero:          +0
+Negative Zero: -0
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_naive_dates() {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    #[derive(Descriptor)]
+    struct Schedule {
+        day: NaiveDate,
+        starts_at: NaiveDateTime,
+        time: NaiveTime,
+    }
+
+    let day = NaiveDate::from_ymd_opt(2021, 9, 15).unwrap();
+    let time = NaiveTime::from_hms_opt(10, 30, 5).unwrap();
+    let description = object_describe_to_string(&Schedule {
+        day,
+        starts_at: day.and_time(time),
+        time,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Day:       2021-09-15
+Starts At: 2021-09-15 10:30:05
+Time:      10:30:05
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_flatten_option_struct() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        #[descriptor(flatten)]
+        address: Option<Address>,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        street: String,
+        postal_code: String,
+    }
+
+    let description = object_describe_to_string(&User {
+        name: "John".to_string(),
+        address: Some(Address {
+            street: "Main Street".to_string(),
+            postal_code: "75001".to_string(),
+        }),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Name:        John
+Street:      Main Street
+Postal Code: 75001
+"#,
+        no_color(description)
+    );
+
+    let description = object_describe_to_string(&User {
+        name: "John".to_string(),
+        address: None,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Name:        John
+Address:     ~
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_date_with_offset() {
+    use chrono::{DateTime, FixedOffset, TimeZone};
+
+    #[derive(Descriptor)]
+    struct Event {
+        created_at: DateTime<FixedOffset>,
+    }
+
+    let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    let description = object_describe_to_string(&Event {
+        created_at: offset.with_ymd_and_hms(2021, 9, 15, 10, 30, 0).unwrap(),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Created At: 15-09-21 10:30:00 +02:00
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_empty_message() {
+    #[derive(Descriptor)]
+    struct Garage {
+        name: String,
+        #[descriptor(empty = "no cars")]
+        cars: Vec<String>,
+    }
+
+    let description = object_describe_to_string(&Garage {
+        name: "Home".to_string(),
+        cars: vec![],
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Name: Home
+Cars: no cars
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_describe_to_string() {
+    use descriptor::Describe;
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    let user = User {
+        name: "John".to_string(),
+        age: 42,
+    };
+    assert_eq!(
+        object_describe_to_string(&user).unwrap(),
+        user.describe_to_string().unwrap()
+    );
+    assert_eq!(
+        r#"
+Name: John
+Age:  42
+"#,
+        no_color(user.describe_to_string().unwrap())
+    );
+}
+
+#[test]
+fn test_describe_options() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        email: Option<String>,
+        address: Address,
+        tags: Vec<String>,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        town: String,
+    }
+
+    let description = object_describe_with_options(
+        &User {
+            name: "Adrien".to_string(),
+            email: None,
+            address: Address {
+                town: "NY".to_string(),
+            },
+            tags: vec![],
+        },
+        &DescribeOptions {
+            null_placeholder: "n/a".to_string(),
+            indent_width: 4,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        r#"
+Name:    Adrien
+Email:   n/a
+Address:
+    Town: NY
+Tags:    n/a
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_pipe() {
+    #[derive(Descriptor)]
+    struct Check {
+        #[descriptor(pipe(uppercase, prefix = "[", suffix = "]"))]
+        status: String,
+        #[descriptor(pipe(suffix = " ms", prefix = "~"))]
+        latency: u64,
+    }
+
+    let description = object_describe_to_string(&Check {
+        status: "ok".to_string(),
+        latency: 12,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Status:  [OK]
+Latency: ~12 ms
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_control_flow() {
+    use std::ops::ControlFlow;
+
+    #[derive(Descriptor)]
+    struct Step {
+        next: ControlFlow<String, u32>,
+        stop: ControlFlow<String, u32>,
+    }
+
+    let description = object_describe_to_string(&Step {
+        next: ControlFlow::Continue(2),
+        stop: ControlFlow::Break("done".to_string()),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Next: Continue(2)
+Stop: Break(done)
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_color() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        town: String,
+    }
+
+    let user = User {
+        name: "Adrien".to_string(),
+        address: Address {
+            town: "NY".to_string(),
+        },
+    };
+    let colored = object_describe_with_options(
+        &user,
+        &DescribeOptions {
+            color: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    if std::env::var_os("NO_COLOR").is_none() {
+        assert!(colored.contains("\x1b[2mName\x1b[0m"));
+    }
+    assert_eq!(
+        no_color(object_describe_to_string(&user).unwrap()),
+        no_color(colored)
+    );
+}
+
+#[test]
+fn test_color_attribute() {
+    #[derive(Descriptor)]
+    struct Check {
+        #[descriptor(color = colorize)]
+        status: String,
+        name: String,
+    }
+
+    fn colorize(status: &str) -> String {
+        format!("\x1b[31m{}\x1b[0m", status)
+    }
+
+    let description = object_describe_to_string(&Check {
+        status: "ERROR".to_string(),
+        name: "disk".to_string(),
+    })
+    .unwrap();
+    assert!(description.contains("\x1b[31mERROR\x1b[0m"));
+    assert_eq!(
+        r#"
+Status: ERROR
+Name:   disk
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_max_width() {
+    #[derive(Descriptor)]
+    struct Issue {
+        #[descriptor(max_width = 10)]
+        title: String,
+        #[descriptor(max_width = 10)]
+        state: String,
+    }
+
+    let description = object_describe_to_string(&Issue {
+        title: "A very long issue title".to_string(),
+        state: "open".to_string(),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Title: A very lo…
+State: open
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_machine_key() {
+    use descriptor::object_describe_json_to_string;
+
+    #[derive(Descriptor)]
+    struct User {
+        #[descriptor(key = "first_name", rename_header = "FIRST")]
+        name: String,
+        #[descriptor(key = "home")]
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        #[descriptor(key = "zip")]
+        postal_code: String,
+        town: String,
+    }
+
+    let user = User {
+        name: "Adrien".to_string(),
+        address: Address {
+            postal_code: "75001".to_string(),
+            town: "Paris".to_string(),
+        },
+    };
+    assert_eq!(
+        r#"{"first_name":"Adrien","home.town":"Paris","home.zip":"75001"}"#,
+        object_describe_json_to_string(&user).unwrap()
+    );
+}
+
+#[test]
+fn test_order() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        email: String,
+        #[descriptor(order = 0)]
+        id: u32,
+    }
+
+    let user = User {
+        name: "Adrien".to_string(),
+        email: "adrien@xcid.fr".to_string(),
+        id: 1,
+    };
+    assert_eq!(
+        r#"
+Id:    1
+Name:  Adrien
+Email: adrien@xcid.fr
+"#,
+        no_color(object_describe_to_string(&user).unwrap())
+    );
+    assert_eq!(
+        "id=1 name=Adrien email=adrien@xcid.fr",
+        object_describe_oneline_to_string(&user).unwrap()
+    );
+}
+
+#[test]
+fn test_order_slots() {
+    #[derive(Descriptor)]
+    struct Row {
+        #[descriptor(skip)]
+        _hidden: u32,
+        a: u32,
+        b: u32,
+        #[descriptor(order = 0)]
+        c: u32,
+        #[descriptor(order = 1)]
+        d: u32,
+        #[descriptor(order = 3)]
+        e: u32,
+    }
+
+    let row = Row {
+        _hidden: 0,
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+        e: 5,
+    };
+    assert_eq!(
+        "c=3 d=4 a=1 e=5 b=2",
+        object_describe_oneline_to_string(&row).unwrap()
+    );
+}
+
+#[test]
+fn test_skip_if_none() {
+    use descriptor::table_describe_to_string;
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        #[descriptor(skip_if_none)]
+        email: Option<String>,
+        #[descriptor(skip_if_none)]
+        phone: Option<String>,
+    }
+
+    let user = User {
+        name: "Adrien".to_string(),
+        email: Some("adrien@xcid.fr".to_string()),
+        phone: None,
+    };
+    assert_eq!(
+        r#"
+Name:  Adrien
+Email: adrien@xcid.fr
+"#,
+        no_color(object_describe_to_string(&user).unwrap())
+    );
+    assert_eq!(
+        r#"NAME   EMAIL          PHONE
+Adrien adrien@xcid.fr ~
+"#,
+        no_color(table_describe_to_string(&[user]).unwrap())
+    );
+}
+
+#[test]
+fn test_skip_if_empty() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        #[descriptor(skip_if_empty)]
+        roles: Vec<String>,
+        #[descriptor(skip_if_empty)]
+        groups: Vec<String>,
+        #[descriptor(skip_if_empty)]
+        nickname: String,
+    }
+
+    let user = User {
+        name: "Adrien".to_string(),
+        roles: vec!["admin".to_string()],
+        groups: vec![],
+        nickname: String::new(),
+    };
+    assert_eq!(
+        r#"
+Name:  Adrien
+Roles:
+- admin
+"#,
+        no_color(object_describe_to_string(&user).unwrap())
+    );
+}
+
+#[test]
+fn test_output_table_headers() {
+    #[derive(Descriptor)]
+    struct Car {
+        name: String,
+        seat: i16,
+        color: String,
+    }
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        #[descriptor(output_table = ["name", "seat"])]
+        cars: Vec<Car>,
+    }
+
+    let description = object_describe_to_string(&User {
+        name: "Adrien".to_string(),
+        cars: vec![
+            Car {
+                name: "Audi".to_string(),
+                seat: 4,
+                color: "red".to_string(),
+            },
+            Car {
+                name: "Mercedes".to_string(),
+                seat: 2,
+                color: "blue".to_string(),
+            },
+        ],
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Name: Adrien
+Cars:
+  NAME       SEAT
+  Audi       4
+  Mercedes   2
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_display() {
+    use std::fmt;
+
+    struct Version(u32, u32);
+
+    impl fmt::Display for Version {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "v{}.{}", self.0, self.1)
+        }
+    }
+
+    #[derive(Descriptor)]
+    struct Package {
+        #[descriptor(display)]
+        version: Version,
+        #[descriptor(display, resolve_option)]
+        latest: Option<Version>,
+        #[descriptor(display, resolve_option)]
+        previous: Option<Version>,
+    }
+
+    let description = object_describe_to_string(&Package {
+        version: Version(1, 2),
+        latest: Some(Version(2, 0)),
+        previous: None,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Version:  v1.2
+Latest:   v2.0
+Previous: ~
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_debug() {
+    #[derive(Debug)]
+    enum Level {
+        Warning,
+    }
+
+    #[derive(Descriptor)]
+    struct Log {
+        #[descriptor(debug)]
+        level: Level,
+        #[descriptor(debug)]
+        tags: Vec<&'static str>,
+    }
+
+    let description = object_describe_to_string(&Log {
+        level: Level::Warning,
+        tags: vec!["disk", "io"],
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Level: Warning
+Tags:  ["disk", "io"]
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_humanize_bytes() {
+    #[derive(Descriptor)]
+    struct File {
+        #[descriptor(map = descriptor::helpers::humanize_bytes)]
+        size: u64,
+        #[descriptor(map = descriptor::helpers::humanize_bytes)]
+        header: u64,
+        #[descriptor(map = descriptor::helpers::humanize_bytes, resolve_option)]
+        disk: Option<u64>,
+    }
+
+    let description = object_describe_to_string(&File {
+        size: 1536,
+        header: 512,
+        disk: Some(3 * 1024 * 1024 * 1024),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Size:   1.5 KiB
+Header: 512 B
+Disk:   3.0 GiB
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_humanize_duration() {
+    #[derive(Descriptor)]
+    struct Job {
+        #[descriptor(map = descriptor::helpers::humanize_duration)]
+        duration_secs: u64,
+        #[descriptor(map = descriptor::helpers::humanize_duration)]
+        timeout_secs: u64,
+        #[descriptor(map = descriptor::helpers::humanize_duration)]
+        retry_secs: u64,
+    }
+
+    let description = object_describe_to_string(&Job {
+        duration_secs: 2 * 3600 + 3 * 60 + 10,
+        timeout_secs: 7200,
+        retry_secs: 45,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Duration Secs: 2h 3m
+Timeout Secs:  2h
+Retry Secs:    45s
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_humanize_duration_rounding() {
+    use descriptor::helpers::humanize_duration;
+
+    assert_eq!("1d", humanize_duration(&86399));
+    assert_eq!("2d", humanize_duration(&172799));
+    assert_eq!("2h", humanize_duration(&7199));
+    assert_eq!("213503982334601d 7h", humanize_duration(&u64::MAX));
+}
+
+#[test]
+fn test_truncate_helper() {
+    #[derive(Descriptor)]
+    struct Issue {
+        #[descriptor(map = descriptor::helpers::truncate(12))]
+        title: String,
+        #[descriptor(map = descriptor::helpers::truncate(12))]
+        author: String,
+    }
+
+    let description = object_describe_to_string(&Issue {
+        title: "Crash héhé when the config is missing".to_string(),
+        author: "adrien".to_string(),
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Title:  Crash héhé …
+Author: adrien
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_result() {
+    use descriptor::table_describe_to_string;
+
+    #[derive(Descriptor)]
+    struct Response {
+        status: Result<u16, String>,
+    }
+
+    let responses = vec![
+        Response { status: Ok(200) },
+        Response {
+            status: Err("timeout".to_string()),
+        },
+    ];
+    assert_eq!(
+        "\nStatus: 200\n",
+        no_color(object_describe_to_string(&responses[0]).unwrap())
+    );
+    assert_eq!(
+        "\nStatus: timeout\n",
+        no_color(object_describe_to_string(&responses[1]).unwrap())
+    );
+    assert_eq!(
+        r#"STATUS
+200
+Error: timeout
+"#,
+        no_color(table_describe_to_string(&responses).unwrap())
+    );
+}
+
+#[test]
+fn test_hashmap_number_keys() {
+    #[derive(Descriptor)]
+    struct Ports {
+        services: HashMap<u32, String>,
+    }
+
+    let services = vec![
+        (8080, "http".to_string()),
+        (22, "ssh".to_string()),
+        (443, "https".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    let description = object_describe_to_string(&Ports { services }).unwrap();
+    assert_eq!(
+        r#"
+Services:
+  22:   ssh
+  443:  https
+  8080: http
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn test_indexmap() {
+    use indexmap::IndexMap;
+
+    #[derive(Descriptor)]
+    struct Config {
+        env: IndexMap<String, String>,
+    }
+
+    let mut env = IndexMap::new();
+    env.insert("b".to_string(), "2".to_string());
+    env.insert("a".to_string(), "1".to_string());
+    env.insert("c".to_string(), "3".to_string());
+    let description = object_describe_to_string(&Config { env }).unwrap();
+    assert_eq!(
+        r#"
+Env:
+  b: 2
+  a: 1
+  c: 3
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_numbered() {
+    #[derive(Descriptor)]
+    struct Recipe {
+        #[descriptor(numbered)]
+        steps: Vec<String>,
+        #[descriptor(numbered)]
+        items: Vec<Item>,
+        tags: Vec<String>,
+    }
+
+    #[derive(Descriptor)]
+    struct Item {
+        name: String,
+        quantity: u32,
+    }
+
+    let description = object_describe_to_string(&Recipe {
+        steps: vec!["Boil".to_string(), "Mix".to_string(), "Serve".to_string()],
+        items: (1..=10)
+            .map(|quantity| Item {
+                name: format!("item{}", quantity),
+                quantity,
+            })
+            .collect(),
+        tags: vec!["quick".to_string()],
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Steps:
+1. Boil
+2. Mix
+3. Serve
+Items:
+ 1. Name:     item1
+    Quantity: 1
+ 2. Name:     item2
+    Quantity: 2
+ 3. Name:     item3
+    Quantity: 3
+ 4. Name:     item4
+    Quantity: 4
+ 5. Name:     item5
+    Quantity: 5
+ 6. Name:     item6
+    Quantity: 6
+ 7. Name:     item7
+    Quantity: 7
+ 8. Name:     item8
+    Quantity: 8
+ 9. Name:     item9
+    Quantity: 9
+10. Name:     item10
+    Quantity: 10
+Tags:
+- quick
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_bool_labels() {
+    #[derive(Descriptor)]
+    struct Feature {
+        #[descriptor(bool_labels = "✓/✗")]
+        enabled: bool,
+        #[descriptor(bool_labels = "Yes/No")]
+        beta: bool,
+        #[descriptor(bool_labels = "Yes/No", resolve_option)]
+        stable: Option<bool>,
+        #[descriptor(bool_labels = "Yes/No", resolve_option)]
+        deprecated: Option<bool>,
+    }
+
+    let description = object_describe_to_string(&Feature {
+        enabled: true,
+        beta: false,
+        stable: Some(true),
+        deprecated: None,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Enabled:    ✓
+Beta:       No
+Stable:     Yes
+Deprecated: ~
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_precision() {
+    #[derive(Descriptor)]
+    struct Circle {
+        #[descriptor(precision = 2)]
+        pi: f64,
+        #[descriptor(precision = 0)]
+        radius: f32,
+        #[descriptor(precision = 1, resolve_option)]
+        area: Option<f64>,
+        ratio: f64,
+    }
+
+    let description = object_describe_to_string(&Circle {
+        pi: std::f64::consts::PI,
+        radius: 2.5,
+        area: Some(19.634),
+        ratio: 0.5,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+Pi:     3.14
+Radius: 2
+Area:   19.6
+Ratio:  0.5
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_thousands() {
+    #[derive(Descriptor)]
+    struct Stats {
+        #[descriptor(thousands)]
+        downloads: u64,
+        #[descriptor(thousands, thousands_sep = ".")]
+        views: u64,
+        #[descriptor(thousands)]
+        balance: i64,
+        #[descriptor(thousands)]
+        stars: u32,
+    }
+
+    let stats = Stats {
+        downloads: 1234567,
+        views: 1000,
+        balance: -123456,
+        stars: 999,
+    };
+    assert_eq!(
+        r#"
+Downloads: 1,234,567
+Views:     1.000
+Balance:   -123,456
+Stars:     999
+"#,
+        no_color(object_describe_to_string(&stats).unwrap())
+    );
+    assert_eq!(
+        r#"Downloads  1,234,567
+Views          1.000
+Balance     -123,456
+Stars            999
+"#,
+        object_describe_ledger_to_string(&stats, 20).unwrap()
+    );
+}
+
+#[test]
+fn test_thousands_float() {
+    #[derive(Descriptor)]
+    struct Account {
+        #[descriptor(thousands)]
+        balance: f64,
+        #[descriptor(thousands)]
+        debt: f64,
+        #[descriptor(thousands)]
+        fee: f32,
+    }
+
+    let account = Account {
+        balance: 1234.5,
+        debt: -1234567.25,
+        fee: 999.5,
+    };
+    assert_eq!(
+        r#"
+Balance: 1,234.5
+Debt:    -1,234,567.25
+Fee:     999.5
+"#,
+        no_color(object_describe_to_string(&account).unwrap())
+    );
+}
+
+#[test]
+fn test_group_thousands_chars() {
+    assert_eq!("a,ébc", descriptor::group_thousands("aébc", ","));
+}
+
+#[test]
+fn test_context_new() {
+    use descriptor::{Context, Describer};
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        town: String,
+    }
+
+    let mut writer = Vec::new();
+    Describer::describe_object(
+        &User {
+            name: "Adrien".to_string(),
+            address: Address {
+                town: "NY".to_string(),
+            },
+        },
+        &mut writer,
+        Context::new(4, 4),
+    )
+    .unwrap();
+    assert_eq!(
+        r#"
+    Name:    Adrien
+    Address:
+        Town: NY
+"#,
+        no_color(String::from_utf8(writer).unwrap())
+    );
+}
+
+#[test]
+fn test_to_field_map() {
+    use descriptor::Describe;
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        street: String,
+        town: String,
+    }
+
+    let map = User {
+        name: "Adrien".to_string(),
+        address: Address {
+            street: "Main street".to_string(),
+            town: "NY".to_string(),
+        },
+    }
+    .to_field_map();
+    assert_eq!(3, map.len());
+    assert_eq!(Some(&"NY".to_string()), map.get("address.town"));
+    assert_eq!(Some(&"Adrien".to_string()), map.get("name"));
+}
+
+#[cfg(feature = "display-fallback")]
+#[test]
+fn test_describe_via_display() {
+    use descriptor::DescribeViaDisplay;
+    use std::num::Wrapping;
+
+    #[derive(Descriptor)]
+    struct Counter {
+        name: String,
+        value: DescribeViaDisplay<Wrapping<u8>>,
+    }
+
+    let counter = Counter {
+        name: "overflow".to_string(),
+        value: DescribeViaDisplay(Wrapping(250u8) + Wrapping(10)),
+    };
+    let description = object_describe_to_string(&counter).unwrap();
+    assert_eq!(
+        r#"
+Name:  overflow
+Value: 4
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_transparent() {
+    #[derive(Descriptor)]
+    #[descriptor(transparent)]
+    struct UserId(String);
+
+    #[derive(Descriptor)]
+    struct Account {
+        id: UserId,
+        name: String,
+    }
+
+    assert_eq!(
+        object_describe_to_string(&"x".to_string()).unwrap(),
+        object_describe_to_string(&UserId("x".to_string())).unwrap()
+    );
+
+    let account = Account {
+        id: UserId("x".to_string()),
+        name: "Adrien".to_string(),
+    };
+    let description = object_describe_to_string(&account).unwrap();
+    assert_eq!(
+        r#"
+Id:   x
+Name: Adrien
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_transparent_phantom_data() {
+    use std::marker::PhantomData;
+
+    #[derive(Descriptor)]
+    #[descriptor(transparent)]
+    struct Id<T>(u32, PhantomData<T>);
+
+    #[derive(Descriptor)]
+    #[descriptor(transparent)]
+    struct Name {
+        value: String,
+        #[descriptor(skip)]
+        _cache: Option<String>,
+    }
+
+    assert_eq!(
+        object_describe_to_string(&42u32).unwrap(),
+        object_describe_to_string(&Id::<String>(42, PhantomData)).unwrap()
+    );
+    assert_eq!(
+        object_describe_to_string(&"x".to_string()).unwrap(),
+        object_describe_to_string(&Name {
+            value: "x".to_string(),
+            _cache: None,
+        })
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_max_depth() {
+    #[derive(Descriptor)]
+    struct Node {
+        child: Option<Box<Node>>,
+    }
+
+    let mut node = Node { child: None };
+    for _ in 0..200 {
+        node = Node {
+            child: Some(Box::new(node)),
+        };
+    }
+
+    let description = object_describe_to_string(&node).unwrap();
+    let lines = description.lines().collect::<Vec<_>>();
+    assert_eq!(66, lines.len());
+    assert_eq!("Child: …", lines.last().unwrap().trim_start());
+}
+
+#[test]
+fn test_method_args() {
+    #[derive(Descriptor)]
+    struct Progress {
+        done: usize,
+        total: usize,
+    }
+
+    impl Progress {
+        fn render(&self, width: usize) -> String {
+            let filled = width * self.done / self.total;
+            format!("[{}{}]", "#".repeat(filled), " ".repeat(width - filled))
+        }
+    }
+
+    fn percent(progress: &Progress, precision: usize) -> String {
+        format!(
+            "{:.*}%",
+            precision,
+            100.0 * progress.done as f64 / progress.total as f64
+        )
+    }
+
+    #[derive(Descriptor)]
+    struct Job {
+        #[descriptor(map = render, method, args(8))]
+        bar: Progress,
+        #[descriptor(map = percent, args(1))]
+        progress: Progress,
+    }
+
+    let job = Job {
+        bar: Progress { done: 1, total: 4 },
+        progress: Progress { done: 1, total: 3 },
+    };
+    let description = object_describe_to_string(&job).unwrap();
+    assert_eq!(
+        r#"
+Bar:      [##      ]
+Progress: 33.3%
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_fallible_map() {
+    #[derive(Descriptor)]
+    struct Config {
+        #[descriptor(map = parse_port, fallible)]
+        port: String,
+        #[descriptor(map = parse_port, fallible)]
+        admin_port: String,
+    }
+
+    fn parse_port(port: &str) -> Result<String, std::num::ParseIntError> {
+        port.parse::<u16>().map(|port| format!(":{}", port))
+    }
+
+    let config = Config {
+        port: "8080".to_string(),
+        admin_port: "80a".to_string(),
+    };
+    let description = object_describe_to_string(&config).unwrap();
+    assert_eq!(
+        r#"
+Port:       :8080
+Admin Port: Error: invalid digit found in string
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_field_default() {
+    #[derive(Descriptor)]
+    struct Server {
+        name: String,
+        #[descriptor(default = "unknown")]
+        region: Option<String>,
+        zone: Option<String>,
+        #[descriptor(default = "none")]
+        tags: Vec<String>,
+    }
+
+    let server = Server {
+        name: "web".to_string(),
+        region: None,
+        zone: None,
+        tags: vec![],
+    };
+    let description = object_describe_to_string(&server).unwrap();
+    assert_eq!(
+        r#"
+Name:   web
+Region: unknown
+Zone:   ~
+Tags:   none
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_borrowed_str() {
+    #[derive(Descriptor)]
+    struct User<'a> {
+        name: &'a str,
+        town: Option<&'a str>,
+    }
+
+    let name = String::from("Adrien");
+    let user = User {
+        name: &name,
+        town: Some("NY"),
+    };
+    let description = object_describe_to_string(&user).unwrap();
+    assert_eq!(
+        r#"
+Name: Adrien
+Town: NY
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_array_field() {
+    #[derive(Descriptor)]
+    struct Triangle {
+        sides: [i32; 3],
+        angles: [i32; 0],
+    }
+
+    let triangle = Triangle {
+        sides: [3, 4, 5],
+        angles: [],
+    };
+    let description = object_describe_to_string(&triangle).unwrap();
+    assert_eq!(
+        r#"
+Sides:
+- 3
+- 4
+- 5
+Angles: ~
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_slice() {
+    fn describe_tags(tags: &[String]) -> String {
+        let mut vec = Vec::new();
+        descriptor::object_describe(&tags, &mut vec).unwrap();
+        String::from_utf8(vec).unwrap()
+    }
+
+    let tags = ["rust".to_string(), "cli".to_string()];
+    assert_eq!(
+        r#"
+- rust
+- cli
+"#,
+        no_color(describe_tags(&tags[..]))
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_object_describe_async() {
+    use descriptor::object_describe_async;
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    let user = User {
+        name: "Adrien".to_string(),
+        age: 32,
+    };
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    object_describe_async(&user, &mut buffer).await.unwrap();
+    assert_eq!(
+        object_describe_to_string(&user).unwrap(),
+        String::from_utf8(buffer.into_inner()).unwrap()
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_json_value() {
+    use serde_json::json;
+
+    #[derive(Descriptor)]
+    struct Event {
+        name: String,
+        payload: serde_json::Value,
+    }
+
+    let event = Event {
+        name: "deploy".to_string(),
+        payload: json!({
+            "version": "1.2.0",
+            "replicas": 3,
+            "rollback": null,
+            "target": {"region": "eu", "zones": ["a", "b"]},
+        }),
+    };
+    let description = object_describe_to_string(&event).unwrap();
+    assert_eq!(
+        r#"
+Name:    deploy
+Payload:
+  replicas: 3
+  rollback: ~
+  target:
+    region: eu
+    zones:
+    - a
+    - b
+  version:  1.2.0
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid() {
+    use uuid::Uuid;
+
+    #[derive(Descriptor)]
+    struct Order {
+        id: Uuid,
+    }
+
+    let order = Order {
+        id: Uuid::from_u128(0x67E5_5044_10B1_426F_9247_BB68_0E5F_E0C8),
+    };
+    let description = object_describe_to_string(&order).unwrap();
+    assert_eq!(
+        r#"
+Id: 67e55044-10b1-426f-9247-bb680e5fe0c8
+"#,
+        no_color(description)
+    );
+    assert_eq!(36, descriptor::Describe::to_field(&order.id, "").len());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_os_string() {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    #[derive(Descriptor)]
+    struct Variable {
+        name: OsString,
+        value: OsString,
+    }
+
+    let variable = Variable {
+        name: OsString::from("LANG"),
+        value: OsString::from_vec(vec![b'f', b'r', 0x80, b'_', b'F', b'R']),
+    };
+    let description = object_describe_to_string(&variable).unwrap();
+    assert_eq!(
+        r#"
+Name:  LANG
+Value: fr�_FR
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_color_null() {
+    #[derive(Descriptor)]
+    struct User {
+        name: Option<String>,
+        tags: Vec<String>,
+        labels: HashMap<String, String>,
+    }
+
+    let user = User {
+        name: None,
+        tags: vec![],
+        labels: HashMap::new(),
+    };
+    let colored = object_describe_with_options(
+        &user,
+        &DescribeOptions {
+            color: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    if std::env::var_os("NO_COLOR").is_none() {
+        assert_eq!(3, colored.matches("\x1b[2m~\x1b[0m").count());
+    }
+    assert_eq!(
+        no_color(object_describe_to_string(&user).unwrap()),
+        no_color(colored)
+    );
+}
+
+#[test]
+fn test_compact() {
+    use descriptor::object_describe_compact_to_string;
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    let user = User {
+        name: "Adrien".to_string(),
+        tags: vec!["admin".to_string()],
+    };
+    let description = object_describe_compact_to_string(&user).unwrap();
+    assert_eq!(
+        r#"Name: Adrien
+Tags:
+- admin
+"#,
+        no_color(description)
+    );
+
+    let description = object_describe_compact_to_string(&user.tags).unwrap();
+    assert_eq!("- admin\n", no_color(description));
+}
+
+#[test]
+fn test_compact_nested() {
+    use descriptor::object_describe_compact_to_string;
+
+    #[derive(Descriptor)]
+    struct Address {
+        city: String,
+    }
+
+    #[derive(Descriptor)]
+    struct User {
+        #[descriptor(skip_if_none)]
+        nickname: Option<String>,
+        #[descriptor(flatten)]
+        address: Address,
+        name: String,
+        #[descriptor(flatten)]
+        work: Address,
+    }
+
+    let users = vec![
+        User {
+            nickname: None,
+            address: Address {
+                city: "Paris".to_string(),
+            },
+            name: "Adrien".to_string(),
+            work: Address {
+                city: "Lyon".to_string(),
+            },
+        },
+        User {
+            nickname: Some("Bob".to_string()),
+            address: Address {
+                city: "Nice".to_string(),
+            },
+            name: "Robert".to_string(),
+            work: Address {
+                city: "Nantes".to_string(),
+            },
+        },
+    ];
+    let description = object_describe_compact_to_string(&users).unwrap();
+    assert_eq!(
+        object_describe_to_string(&users).unwrap(),
+        format!("\n{}", description)
+    );
+    let description = object_describe_compact_to_string(&users[0]).unwrap();
+    assert_eq!(
+        object_describe_to_string(&users[0]).unwrap(),
+        format!("\n{}", description)
+    );
+
+    let map = BTreeMap::from([("a", 1), ("b", 2)]);
+    let description = object_describe_compact_to_string(&map).unwrap();
+    assert_eq!("a: 1\nb: 2\n", no_color(description));
+}
+
+#[test]
+fn test_fixed_pad() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+    }
+
+    #[derive(Descriptor)]
+    struct Server {
+        hostname: String,
+        a_very_long_field_name_indeed: u8,
+    }
+
+    let options = DescribeOptions {
+        fixed_pad: Some(20),
+        ..Default::default()
+    };
+    let user = User {
+        name: "Adrien".to_string(),
+    };
+    let server = Server {
+        hostname: "web".to_string(),
+        a_very_long_field_name_indeed: 1,
+    };
+    let description = object_describe_with_options(&user, &options).unwrap()
+        + &object_describe_with_options(&server, &options).unwrap();
+    assert_eq!(
+        r#"
+Name:                Adrien
+
+Hostname:            web
+A Very Long Field Name Indeed: 1
+"#,
+        no_color(description)
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_system_time() {
+    use std::time::{Duration, SystemTime};
+
+    #[derive(Descriptor)]
+    struct File {
+        created: SystemTime,
+        modified: SystemTime,
+    }
+
+    let file = File {
+        created: SystemTime::UNIX_EPOCH - Duration::from_secs(1),
+        modified: SystemTime::UNIX_EPOCH,
+    };
+    let description = object_describe_to_string(&file).unwrap();
+    assert_eq!(
+        r#"
+Created:  1969-12-31T23:59:59Z
+Modified: 1970-01-01T00:00:00Z
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_non_zero() {
+    use std::num::NonZeroU32;
+
+    #[derive(Descriptor)]
+    struct Pool {
+        size: NonZeroU32,
+        replicas: Option<NonZeroU32>,
+    }
+
+    let pool = Pool {
+        size: NonZeroU32::new(8).unwrap(),
+        replicas: NonZeroU32::new(0),
+    };
+    let description = object_describe_to_string(&pool).unwrap();
+    assert_eq!(
+        r#"
+Size:     8
+Replicas: ~
+"#,
+        no_color(description)
+    );
+}
+
+#[test]
+fn test_context_struct_update() {
+    use descriptor::Context;
+
+    let ctx = Context {
+        offset: 4,
+        ..Default::default()
+    };
+    assert_eq!(4, ctx.offset);
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_color_without_feature() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+    }
+
+    let options = DescribeOptions {
+        color: true,
+        zebra: true,
+        ..DescribeOptions::default()
+    };
+    let user = User {
+        name: "Adrien".to_string(),
+    };
+    assert_eq!(
+        object_describe_to_string(&user).unwrap(),
+        object_describe_with_options(&user, &options).unwrap()
+    );
+}
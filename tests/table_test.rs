@@ -1,4 +1,11 @@
-use descriptor::{table_describe_to_string, table_describe_with_header_to_string, Descriptor};
+use descriptor::{
+    headers_of, label_of, table_describe_counts_to_string, table_describe_fit_to_string,
+    table_describe_grid_to_string, table_describe_grouped_to_string,
+    table_describe_limited_to_string, table_describe_strict_to_string, table_describe_to_string,
+    table_describe_vertical_to_string, table_describe_with_footer_to_string,
+    table_describe_with_header_to_string, table_rows, validate_headers, Context, Describe,
+    Describer, Descriptor,
+};
 
 pub fn no_color_and_line_return(str: String) -> String {
     format!(
@@ -75,8 +82,7 @@ row2  row         s
         no_color_and_line_return(table)
     );
 
-    let table =
-        table_describe_with_header_to_string(&list, &vec!["hidden_one".to_string()]).unwrap();
+    let table = table_describe_with_header_to_string(&list, &["hidden_one".to_string()]).unwrap();
 
     assert_eq!(
         r#"
@@ -140,7 +146,7 @@ fn test_table_inner() {
         parent: "parent".to_string(),
     };
 
-    let table = table_describe_to_string(&vec![foo]).unwrap();
+    let table = table_describe_to_string(&[foo]).unwrap();
     assert_eq!(
         r#"
 INNER_FOO.STRING PARENT
@@ -175,7 +181,7 @@ fn test_into_field_level() {
             }
         }
     }
-    let table = table_describe_to_string(&vec![Foo {
+    let table = table_describe_to_string(&[Foo {
         foo: Bar {
             foo: "a".to_string(),
             bar: "b".to_string(),
@@ -214,7 +220,7 @@ fn test_extra_fields() {
         }
     }
 
-    let table = table_describe_to_string(&vec![Foo {
+    let table = table_describe_to_string(&[Foo {
         first_field: "test".to_string(),
         number: 200,
     }])
@@ -245,7 +251,7 @@ fn test_map_all() {
         }
     }
 
-    let table = table_describe_to_string(&vec![
+    let table = table_describe_to_string(&[
         Foo {
             first_field: "test".to_string(),
             number: 200,
@@ -265,3 +271,1543 @@ test        1
         no_color_and_line_return(table)
     );
 }
+
+#[test]
+fn test_enum_with_data() {
+    #[derive(Descriptor)]
+    struct Job {
+        name: String,
+        status: Status,
+    }
+
+    #[derive(Descriptor)]
+    enum Status {
+        Failed(String),
+        Active { since: u32 },
+    }
+
+    let table = table_describe_to_string(&[
+        Job {
+            name: "build".to_string(),
+            status: Status::Failed("timeout".to_string()),
+        },
+        Job {
+            name: "deploy".to_string(),
+            status: Status::Active { since: 10 },
+        },
+    ])
+    .unwrap();
+    assert_eq!(
+        r#"
+NAME   STATUS
+build  Failed: timeout
+deploy Active: since=10
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_flatten_option() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        #[descriptor(flatten_option = false)]
+        address: Option<Address>,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        street: String,
+        town: String,
+    }
+
+    let table = table_describe_to_string(&[
+        User {
+            name: "Adrien".to_string(),
+            address: Some(Address {
+                street: "Main".to_string(),
+                town: "NY".to_string(),
+            }),
+        },
+        User {
+            name: "Corentin".to_string(),
+            address: None,
+        },
+    ])
+    .unwrap();
+    assert_eq!(
+        r#"
+NAME     ADDRESS
+Adrien   street=Main town=NY
+Corentin ~
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_label_for() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        street: String,
+        #[descriptor(rename_header = "City")]
+        town: String,
+    }
+
+    assert_eq!("City", User::label_for("address.town"));
+    assert_eq!("ADDRESS.STREET", label_of::<User>("address.street"));
+    assert_eq!("UNKNOWN_FIELD", label_of::<User>("unknown_field"));
+}
+
+#[test]
+fn test_tuple_field() {
+    #[derive(Descriptor)]
+    struct Foo {
+        name: String,
+        pair: (String, i32),
+    }
+
+    let table = table_describe_to_string(&[Foo {
+        name: "foo".to_string(),
+        pair: ("answer".to_string(), 42),
+    }])
+    .unwrap();
+    assert_eq!(
+        r#"
+NAME PAIR
+foo  answer,42
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_json_inline() {
+    use descriptor::object_describe_to_string;
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        #[descriptor(json_inline)]
+        cars: Vec<Car>,
+    }
+
+    #[derive(Descriptor, serde::Serialize)]
+    struct Car {
+        brand: String,
+        seat: i16,
+    }
+
+    let user = User {
+        name: "Adrien".to_string(),
+        cars: vec![
+            Car {
+                brand: "Audi".to_string(),
+                seat: 4,
+            },
+            Car {
+                brand: "Mercedes".to_string(),
+                seat: 2,
+            },
+        ],
+    };
+
+    let description = object_describe_to_string(&user).unwrap();
+    assert_eq!(
+        r#"
+Name: Adrien
+Cars:
+- Brand: Audi
+  Seat:  4
+- Brand: Mercedes
+  Seat:  2
+"#,
+        description
+    );
+
+    let table = table_describe_to_string(&[user]).unwrap();
+    assert_eq!(
+        r#"
+NAME   CARS
+Adrien [{"brand":"Audi","seat":4},{"brand":"Mercedes","seat":2}]
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_large_array() {
+    #[derive(Descriptor)]
+    struct File {
+        name: String,
+        hash: [u8; 64],
+    }
+
+    let mut hash = [0u8; 64];
+    hash[63] = 255;
+
+    let table = table_describe_to_string(&[File {
+        name: "debian.iso".to_string(),
+        hash,
+    }])
+    .unwrap();
+    let expected_hash = format!("{}255", "0,".repeat(63));
+    assert_eq!(
+        format!("\nNAME       HASH\ndebian.iso {}\n", expected_hash),
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_table_rows() {
+    #[derive(Descriptor)]
+    struct Car {
+        brand: String,
+        seat: i16,
+    }
+
+    let cars = vec![
+        Car {
+            brand: "Audi".to_string(),
+            seat: 4,
+        },
+        Car {
+            brand: "Mercedes".to_string(),
+            seat: 2,
+        },
+    ];
+
+    assert_eq!(
+        vec![
+            (0, vec!["Audi".to_string(), "4".to_string()]),
+            (1, vec!["Mercedes".to_string(), "2".to_string()]),
+        ],
+        table_rows(&cars, &[])
+    );
+    assert_eq!(
+        vec![(0, vec!["4".to_string()]), (1, vec!["2".to_string()])],
+        table_rows(&cars, &["seat".to_string()])
+    );
+}
+
+#[test]
+fn test_socket_addr() {
+    use std::net::SocketAddr;
+
+    #[derive(Descriptor)]
+    struct Server {
+        name: String,
+        server: SocketAddr,
+    }
+
+    let table = table_describe_to_string(&[
+        Server {
+            name: "v4".to_string(),
+            server: "127.0.0.1:8080".parse().unwrap(),
+        },
+        Server {
+            name: "v6".to_string(),
+            server: "[::1]:443".parse().unwrap(),
+        },
+    ])
+    .unwrap();
+    assert_eq!(
+        r#"
+NAME SERVER
+v4   127.0.0.1:8080
+v6   [::1]:443
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_table_limited() {
+    #[derive(Descriptor)]
+    struct Row {
+        id: usize,
+        name: String,
+    }
+
+    let rows = (1..=10)
+        .map(|id| Row {
+            id,
+            name: format!("row{}", id),
+        })
+        .collect::<Vec<_>>();
+
+    let table = table_describe_limited_to_string(&rows, 3).unwrap();
+    assert_eq!(
+        r#"
+ID NAME
+1  row1
+2  row2
+3  row3
+… and 7 more
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_table_empty_message() {
+    #[derive(Descriptor)]
+    struct Garage {
+        name: String,
+        #[descriptor(empty = "no cars")]
+        cars: Vec<String>,
+    }
+
+    let table = table_describe_to_string(&[
+        Garage {
+            name: "Home".to_string(),
+            cars: vec![],
+        },
+        Garage {
+            name: "Work".to_string(),
+            cars: vec!["Clio".to_string()],
+        },
+    ])
+    .unwrap();
+    assert_eq!(
+        r#"
+NAME CARS
+Home no cars
+Work Clio
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_validate_headers() {
+    #[derive(Descriptor)]
+    struct Row {
+        id: usize,
+        #[descriptor(flatten)]
+        owner: Owner,
+    }
+
+    #[derive(Descriptor)]
+    struct Owner {
+        name: String,
+    }
+
+    let headers = vec!["id".to_string(), "name".to_string()];
+    assert_eq!(Ok(()), validate_headers::<Row>(&headers));
+
+    let headers = vec!["id".to_string(), "nmae".to_string()];
+    assert_eq!(
+        Err(vec!["nmae".to_string()]),
+        validate_headers::<Row>(&headers)
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_table_color() {
+    use descriptor::{Context, Describer};
+
+    #[derive(Descriptor)]
+    struct Row {
+        id: usize,
+        name: String,
+    }
+
+    let rows = vec![Row {
+        id: 1,
+        name: "first".to_string(),
+    }];
+    let mut colored = Vec::new();
+    Describer::describe_list(&rows, &mut colored, Context::default().with_color(true)).unwrap();
+    let colored = String::from_utf8(colored).unwrap();
+    if std::env::var_os("NO_COLOR").is_none() {
+        assert!(colored.contains("\x1b[1mID\x1b[0m"));
+    }
+    assert_eq!(
+        no_color_and_line_return(table_describe_to_string(&rows).unwrap()),
+        no_color_and_line_return(colored)
+    );
+}
+
+#[test]
+fn test_table_color_attribute() {
+    #[derive(Descriptor)]
+    struct Check {
+        #[descriptor(color = colorize)]
+        status: String,
+        name: String,
+    }
+
+    fn colorize(status: &str) -> String {
+        match status {
+            "ERROR" => format!("\x1b[31m{}\x1b[0m", status),
+            _ => status.to_string(),
+        }
+    }
+
+    let table = table_describe_to_string(&[
+        Check {
+            status: "ERROR".to_string(),
+            name: "disk".to_string(),
+        },
+        Check {
+            status: "OK".to_string(),
+            name: "network".to_string(),
+        },
+    ])
+    .unwrap();
+    assert!(table.contains("\x1b[31mERROR\x1b[0m"));
+    assert_eq!(
+        r#"
+STATUS NAME
+ERROR  disk
+OK     network
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_table_zebra() {
+    use descriptor::{table_describe_with_options, DescribeOptions};
+
+    #[derive(Descriptor)]
+    struct Row {
+        id: usize,
+        #[descriptor(color = colorize)]
+        name: String,
+    }
+
+    fn colorize(name: &str) -> String {
+        format!("\x1b[31m{}\x1b[0m", name)
+    }
+
+    let rows = (1..=4)
+        .map(|id| Row {
+            id,
+            name: format!("row{}", id),
+        })
+        .collect::<Vec<_>>();
+    let striped = table_describe_with_options(
+        &rows,
+        &DescribeOptions {
+            color: true,
+            zebra: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    if std::env::var_os("NO_COLOR").is_none() {
+        // The stripe is started again after each cell of the two striped rows
+        assert_eq!(4, striped.matches("\x1b[48;5;236m").count());
+        assert!(striped.contains("\x1b[48;5;236m4\x1b[48;5;236m  \x1b[31mrow4\x1b[0m\x1b[0m"));
+    }
+    assert_eq!(
+        no_color_and_line_return(table_describe_to_string(&rows).unwrap()),
+        no_color_and_line_return(striped)
+    );
+}
+
+#[test]
+fn test_table_counts() {
+    #[derive(Descriptor)]
+    enum Status {
+        Running,
+        Stopped,
+        Failed,
+    }
+
+    let statuses = vec![
+        Status::Stopped,
+        Status::Running,
+        Status::Failed,
+        Status::Running,
+        Status::Running,
+        Status::Failed,
+    ];
+    let table = table_describe_counts_to_string(&statuses).unwrap();
+    assert_eq!(
+        r#"
+VALUE   COUNT
+Running 3
+Failed  2
+Stopped 1
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_table_max_width() {
+    #[derive(Descriptor)]
+    struct Issue {
+        id: usize,
+        #[descriptor(max_width = 10)]
+        description: String,
+        state: String,
+    }
+
+    let table = table_describe_to_string(&[
+        Issue {
+            id: 1,
+            description: "A description that is forty chars long.".to_string(),
+            state: "open".to_string(),
+        },
+        Issue {
+            id: 2,
+            description: "Short".to_string(),
+            state: "closed".to_string(),
+        },
+    ])
+    .unwrap();
+    assert_eq!(
+        r#"
+ID DESCRIPTION STATE
+1  A descrip…  open
+2  Short       closed
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_truncate_colored_value() {
+    assert_eq!(
+        "\x1b[31mA descrip…\x1b[0m",
+        descriptor::truncate("\x1b[31mA description that is long\x1b[0m", 10)
+    );
+    assert_eq!(
+        "\x1b[31mshort\x1b[0m",
+        descriptor::truncate("\x1b[31mshort\x1b[0m", 10)
+    );
+}
+
+#[test]
+fn test_truncate_graphemes() {
+    let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+    let truncated = descriptor::truncate(&format!("ab{}cd", family), 5);
+    // The family is kept whole or dropped, its width depends on the unicode-width version
+    assert!(truncated == "ab…" || truncated == format!("ab{}…", family));
+    assert_eq!(
+        "e\u{301}e\u{301}…",
+        descriptor::truncate("e\u{301}e\u{301}e\u{301}e\u{301}", 3)
+    );
+}
+
+#[test]
+fn test_table_fit() {
+    #[derive(Descriptor)]
+    struct Issue {
+        id: usize,
+        title: String,
+        description: String,
+    }
+
+    let issues = vec![
+        Issue {
+            id: 1,
+            title: "Crash on startup".to_string(),
+            description: "The application crashes when the config is missing".to_string(),
+        },
+        Issue {
+            id: 2,
+            title: "Typo".to_string(),
+            description: "Fix a typo".to_string(),
+        },
+    ];
+    let table = table_describe_fit_to_string(&issues, 40).unwrap();
+    let table = no_color_and_line_return(table);
+    assert!(table.lines().all(|line| line.chars().count() <= 40));
+    assert_eq!(
+        r#"
+ID TITLE            DESCRIPTION
+1  Crash on startup The application cra…
+2  Typo             Fix a typo
+"#,
+        table
+    );
+}
+
+#[test]
+fn test_table_wrap() {
+    #[derive(Descriptor)]
+    struct Issue {
+        id: usize,
+        #[descriptor(wrap = 20)]
+        description: String,
+        state: String,
+    }
+
+    let table = table_describe_to_string(&[
+        Issue {
+            id: 1,
+            description: "The application crashes on startup".to_string(),
+            state: "open".to_string(),
+        },
+        Issue {
+            id: 2,
+            description: "Fix a typo".to_string(),
+            state: "closed".to_string(),
+        },
+    ])
+    .unwrap();
+    assert_eq!(
+        r#"
+ID DESCRIPTION        STATE
+1  The application    open
+   crashes on startup
+2  Fix a typo         closed
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_table_multiline_cell() {
+    #[derive(Descriptor)]
+    struct Commit {
+        hash: String,
+        message: String,
+        author: String,
+    }
+
+    let table = table_describe_to_string(&[
+        Commit {
+            hash: "3dbbfe8".to_string(),
+            message: "Fix tables\n\nCells are split on new lines".to_string(),
+            author: "adrien".to_string(),
+        },
+        Commit {
+            hash: "dcaf45b".to_string(),
+            message: "Add indent".to_string(),
+            author: "bob".to_string(),
+        },
+    ])
+    .unwrap();
+    assert_eq!(
+        r#"
+HASH    MESSAGE                      AUTHOR
+3dbbfe8 Fix tables                   adrien
+
+        Cells are split on new lines
+dcaf45b Add indent                   bob
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_table_vertical() {
+    #[derive(Descriptor)]
+    struct Server {
+        name: String,
+        #[descriptor(rename_header = "IP")]
+        address: String,
+        cpu_count: u32,
+    }
+
+    let table = table_describe_vertical_to_string(&Server {
+        name: "web-1".to_string(),
+        address: "10.0.0.1".to_string(),
+        cpu_count: 4,
+    })
+    .unwrap();
+    assert_eq!(
+        r#"
+FIELD     VALUE
+NAME      web-1
+IP        10.0.0.1
+CPU_COUNT 4
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_table_vertical_header_case() {
+    use descriptor::{table_describe_vertical_with_options, DescribeOptions, HeaderCase};
+
+    #[derive(Descriptor)]
+    struct Server {
+        #[descriptor(rename_header = "IP")]
+        address: String,
+        cpu_count: u32,
+    }
+
+    let options = DescribeOptions {
+        header_case: HeaderCase::Lower,
+        ..DescribeOptions::default()
+    };
+    let table = table_describe_vertical_with_options(
+        &Server {
+            address: "10.0.0.1".to_string(),
+            cpu_count: 4,
+        },
+        &options,
+    )
+    .unwrap();
+    assert_eq!(
+        r#"
+field     value
+IP        10.0.0.1
+cpu count 4
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_table_grid() {
+    #[derive(Descriptor)]
+    struct Row {
+        id: usize,
+        name: String,
+    }
+
+    let table = table_describe_grid_to_string(&[
+        Row {
+            id: 1,
+            name: "first".to_string(),
+        },
+        Row {
+            id: 22,
+            name: "second".to_string(),
+        },
+    ])
+    .unwrap();
+    assert_eq!(
+        r#"+----+--------+
+| ID | NAME   |
++----+--------+
+| 1  | first  |
++----+--------+
+| 22 | second |
++----+--------+
+"#,
+        table
+    );
+
+    let table = table_describe_grid_to_string::<Row>(&[]).unwrap();
+    assert_eq!(
+        r#"+----+-------+
+| ID | NAME  |
++----+-------+
+| Empty list |
++------------+
+"#,
+        table
+    );
+}
+
+#[test]
+fn test_table_grid_with_context() {
+    use descriptor::HeaderCase;
+
+    #[derive(Descriptor)]
+    struct Row {
+        id: usize,
+        full_name: Option<String>,
+    }
+
+    let mut table = Vec::new();
+    Describer::describe_grid(
+        &[Row {
+            id: 1,
+            full_name: None,
+        }],
+        &mut table,
+        Context {
+            offset: 2,
+            ..Context::default()
+        }
+        .with_header_case(HeaderCase::Title)
+        .with_null_placeholder("n/a"),
+    )
+    .unwrap();
+    assert_eq!(
+        r#"
+  +----+-----------+
+  | Id | Full Name |
+  +----+-----------+
+  | 1  | n/a       |
+  +----+-----------+
+"#,
+        no_color_and_line_return(String::from_utf8(table).unwrap())
+    );
+}
+
+#[test]
+fn test_table_join() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        #[descriptor(join = "; ")]
+        roles: Vec<String>,
+        groups: Vec<String>,
+    }
+
+    let table = table_describe_to_string(&[User {
+        name: "Adrien".to_string(),
+        roles: vec!["admin".to_string(), "dev".to_string()],
+        groups: vec!["ops".to_string(), "web".to_string()],
+    }])
+    .unwrap();
+    assert_eq!(
+        r#"
+NAME   ROLES      GROUPS
+Adrien admin; dev ops,web
+"#,
+        no_color_and_line_return(table)
+    );
+}
+
+#[test]
+fn test_unknown_header_error() {
+    #[derive(Descriptor)]
+    struct Row {
+        id: usize,
+        name: String,
+    }
+
+    assert_eq!(
+        vec!["id".to_string(), "name".to_string()],
+        headers_of::<Row>()
+    );
+
+    let rows = vec![Row {
+        id: 1,
+        name: "one".to_string(),
+    }];
+    let err = table_describe_strict_to_string(&rows, &["id".to_string(), "nmae".to_string()])
+        .unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+    assert_eq!(
+        "unknown headers: nmae, valid headers are: id, name",
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_table_footer() {
+    #[derive(Descriptor)]
+    struct Line {
+        item: String,
+        amount: u64,
+    }
+
+    let lines = vec![
+        Line {
+            item: "coffee".to_string(),
+            amount: 3,
+        },
+        Line {
+            item: "sandwich".to_string(),
+            amount: 12,
+        },
+    ];
+    let totals = Line {
+        item: "Total".to_string(),
+        amount: lines.iter().map(|line| line.amount).sum(),
+    };
+
+    let description = table_describe_with_footer_to_string(&lines, &totals).unwrap();
+    assert_eq!(
+        r#"
+ITEM     AMOUNT
+coffee   3
+sandwich 12
+-------- ------
+Total    15
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_grouped() {
+    #[derive(Descriptor)]
+    struct Car {
+        brand: String,
+        model: String,
+        year: u16,
+    }
+
+    let car = |brand: &str, model: &str, year| Car {
+        brand: brand.to_string(),
+        model: model.to_string(),
+        year,
+    };
+    let cars = vec![
+        car("Tesla", "Model 3", 2019),
+        car("Renault", "Zoe", 2020),
+        car("Tesla", "Model S", 2016),
+    ];
+
+    let description = table_describe_grouped_to_string(&cars, "brand").unwrap();
+    assert_eq!(
+        r#"
+Tesla:
+MODEL   YEAR
+Model 3 2019
+Model S 2016
+
+Renault:
+MODEL   YEAR
+Zoe     2020
+"#,
+        no_color_and_line_return(description)
+    );
+
+    let ctx = Context::default().with_strict_headers(true);
+    assert!(Describer::describe_list_grouped(&cars, "color", &mut Vec::new(), ctx).is_err());
+}
+
+#[test]
+fn test_flatten_prefix() {
+    #[derive(Descriptor)]
+    struct Customer {
+        name: String,
+        #[descriptor(flatten, prefix = "home_")]
+        home: Address,
+        #[descriptor(flatten, prefix = "work_")]
+        work: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        street: String,
+        town: String,
+    }
+
+    let customers = vec![Customer {
+        name: "Adrien".to_string(),
+        home: Address {
+            street: "Main street".to_string(),
+            town: "NY".to_string(),
+        },
+        work: Address {
+            street: "Wall street".to_string(),
+            town: "NY".to_string(),
+        },
+    }];
+
+    let description = table_describe_to_string(&customers).unwrap();
+    assert_eq!(
+        r#"
+NAME   HOME_STREET HOME_TOWN WORK_STREET WORK_TOWN
+Adrien Main street NY        Wall street NY
+"#,
+        no_color_and_line_return(description)
+    );
+
+    let description = descriptor::object_describe_to_string(&customers[0]).unwrap();
+    assert_eq!(
+        r#"
+Name:        Adrien
+Home Street: Main street
+Home Town:   NY
+Work Street: Wall street
+Work Town:   NY
+"#,
+        description
+    );
+}
+
+#[test]
+fn test_flatten_table() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        #[descriptor(flatten)]
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        street: String,
+        #[descriptor(rename_header = "CITY")]
+        town: String,
+    }
+
+    let users = vec![User {
+        name: "Adrien".to_string(),
+        address: Address {
+            street: "Main street".to_string(),
+            town: "NY".to_string(),
+        },
+    }];
+
+    let description = table_describe_to_string(&users).unwrap();
+    assert_eq!(
+        r#"
+NAME   STREET      CITY
+Adrien Main street NY
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_header_case() {
+    use descriptor::{table_describe_with_options, DescribeOptions, HeaderCase};
+
+    #[derive(Descriptor)]
+    struct Car {
+        brand: String,
+        top_speed: u32,
+    }
+
+    let cars = vec![Car {
+        brand: "Tesla".to_string(),
+        top_speed: 261,
+    }];
+    let options = DescribeOptions {
+        header_case: HeaderCase::Lower,
+        ..DescribeOptions::default()
+    };
+    let description = table_describe_with_options(&cars, &options).unwrap();
+    assert_eq!(
+        r#"
+brand top speed
+Tesla 261
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_null_placeholder() {
+    use descriptor::{table_describe_with_options, DescribeOptions};
+
+    #[derive(Descriptor)]
+    struct Car {
+        brand: String,
+        owner: Option<String>,
+        #[descriptor(spread = 2)]
+        tags: Vec<String>,
+    }
+
+    let cars = vec![Car {
+        brand: "Tesla".to_string(),
+        owner: None,
+        tags: vec!["electric".to_string()],
+    }];
+    let options = DescribeOptions {
+        null_placeholder: "n/a".to_string(),
+        ..DescribeOptions::default()
+    };
+    let description = table_describe_with_options(&cars, &options).unwrap();
+    assert_eq!(
+        r#"
+BRAND OWNER TAGS_1   TAGS_2
+Tesla n/a   electric n/a
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_null_placeholder_keeps_values() {
+    use descriptor::{table_describe_with_options, DescribeOptions};
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        home: Option<String>,
+    }
+
+    let users = vec![
+        User {
+            name: "Adrien".to_string(),
+            home: Some("~".to_string()),
+        },
+        User {
+            name: "Nina".to_string(),
+            home: None,
+        },
+    ];
+    let options = DescribeOptions {
+        null_placeholder: "n/a".to_string(),
+        ..DescribeOptions::default()
+    };
+    let description = table_describe_with_options(&users, &options).unwrap();
+    assert_eq!(
+        r#"
+NAME   HOME
+Adrien ~
+Nina   n/a
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_of_references() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    let users = [
+        User {
+            name: "Adrien".to_string(),
+            age: 32,
+        },
+        User {
+            name: "Jane".to_string(),
+            age: 17,
+        },
+    ];
+    let adults = users
+        .iter()
+        .filter(|user| user.age >= 18)
+        .collect::<Vec<&User>>();
+
+    let description = table_describe_to_string(&adults).unwrap();
+    assert_eq!(
+        r#"
+NAME   AGE
+Adrien 32
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_table_describe_async() {
+    use descriptor::table_describe_async;
+
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    let users = vec![User {
+        name: "Adrien".to_string(),
+        age: 32,
+    }];
+    let mut buffer = Vec::new();
+    table_describe_async(&users, &[], &mut buffer)
+        .await
+        .unwrap();
+    assert_eq!(
+        table_describe_to_string(&users).unwrap(),
+        String::from_utf8(buffer).unwrap()
+    );
+}
+
+#[test]
+fn test_table_streaming() {
+    use descriptor::table_describe_streaming;
+
+    #[derive(Descriptor)]
+    struct Row {
+        id: usize,
+        name: String,
+    }
+
+    let row = |id| Row {
+        id,
+        name: format!("user-{}", id),
+    };
+    let batch = table_describe_to_string(&(0..10_000).map(row).collect::<Vec<_>>()).unwrap();
+
+    let mut streamed = Vec::new();
+    table_describe_streaming((0..10_000).map(row), Some(&[4, 9]), &mut streamed).unwrap();
+    let streamed = String::from_utf8(streamed).unwrap();
+    assert_eq!(batch.lines().next(), streamed.lines().next());
+    assert_eq!(batch.lines().last(), streamed.lines().last());
+    assert_eq!(batch, streamed);
+
+    // Widths are estimated on the first rows, later wider cells push the rest of their row
+    let mut sampled = Vec::new();
+    table_describe_streaming((0..10_000).map(row), None, &mut sampled).unwrap();
+    let sampled = String::from_utf8(sampled).unwrap();
+    assert_eq!(batch.lines().count(), sampled.lines().count());
+    assert_eq!(Some("ID NAME"), sampled.lines().next());
+    assert_eq!(Some("9999 user-9999"), sampled.lines().last());
+    assert!(sampled.contains("\n99 user-99\n100 user-100\n"));
+}
+
+#[test]
+fn test_doc_comment_labels() {
+    #[derive(Descriptor)]
+    struct Disk {
+        /// Mount point
+        #[descriptor(doc)]
+        path: String,
+        /// Free space
+        ///
+        /// In bytes, as reported by the file system.
+        #[descriptor(doc, rename_header = "FREE")]
+        available: u64,
+    }
+
+    let disks = vec![Disk {
+        path: "/".to_string(),
+        available: 1024,
+    }];
+    let description = table_describe_to_string(&disks).unwrap();
+    assert_eq!(
+        r#"
+Mount point FREE
+/           1024
+"#,
+        no_color_and_line_return(description)
+    );
+
+    let description = descriptor::object_describe_to_string(&disks[0]).unwrap();
+    assert_eq!(
+        r#"
+Mount point: /
+Free space:  1024
+"#,
+        description
+    );
+}
+
+#[test]
+fn test_doc_comment_without_doc_attribute() {
+    #[derive(Descriptor)]
+    struct Disk {
+        /// Mount point
+        path: String,
+    }
+
+    let disks = vec![Disk {
+        path: "/".to_string(),
+    }];
+    let description = table_describe_to_string(&disks).unwrap();
+    assert_eq!(
+        r#"
+PATH
+/
+"#,
+        no_color_and_line_return(description)
+    );
+
+    let description = descriptor::object_describe_to_string(&disks[0]).unwrap();
+    assert_eq!(
+        r#"
+Path: /
+"#,
+        description
+    );
+}
+
+#[test]
+fn test_typo_header_error() {
+    #[derive(Descriptor)]
+    struct Car {
+        brand: String,
+        #[descriptor(flatten)]
+        engine: Engine,
+    }
+
+    #[derive(Descriptor)]
+    struct Engine {
+        power: u32,
+    }
+
+    let cars = vec![Car {
+        brand: "Tesla".to_string(),
+        engine: Engine { power: 300 },
+    }];
+    let headers = vec!["brand".to_string(), "powr".to_string()];
+
+    let err = table_describe_strict_to_string(&cars, &headers).unwrap_err();
+    assert_eq!(
+        "unknown headers: powr, valid headers are: brand, power",
+        err.to_string()
+    );
+    let mut vec = Vec::new();
+    let ctx = Context::default().with_strict_headers(true);
+    assert!(Describer::describe_list_with_header(&cars, &headers, &mut vec, ctx).is_err());
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_headers_not_strict_by_default() {
+    struct Row {
+        id: usize,
+    }
+
+    // Only `id` is listed, `double` is answered by `to_field`
+    impl Describe for Row {
+        fn to_field(&self, field_name: &str) -> String {
+            match field_name {
+                "id" => self.id.to_string(),
+                "double" => (self.id * 2).to_string(),
+                _ => "field not found".to_string(),
+            }
+        }
+
+        fn headers() -> Vec<String> {
+            vec!["id".to_string()]
+        }
+    }
+
+    let rows = vec![Row { id: 21 }];
+    let headers = vec!["id".to_string(), "double".to_string()];
+    let description = table_describe_with_header_to_string(&rows, &headers).unwrap();
+    assert_eq!(
+        r#"
+ID DOUBLE
+21 42
+"#,
+        no_color_and_line_return(description)
+    );
+    assert!(table_describe_strict_to_string(&rows, &headers).is_err());
+}
+
+#[test]
+fn test_min_width() {
+    #[derive(Descriptor)]
+    struct Job {
+        #[descriptor(min_width = 8)]
+        ok: String,
+        #[descriptor(min_width = 2, max_width = 6)]
+        name: String,
+    }
+
+    let jobs = vec![
+        Job {
+            ok: "y".to_string(),
+            name: "build".to_string(),
+        },
+        Job {
+            ok: "n".to_string(),
+            name: "deploy-prod".to_string(),
+        },
+    ];
+    let description = table_describe_to_string(&jobs).unwrap();
+    assert_eq!(
+        r#"
+OK       NAME
+y        build
+n        deplo…
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_of_references_field() {
+    #[derive(Descriptor)]
+    struct User {
+        name: String,
+        age: u32,
+    }
+
+    #[derive(Descriptor)]
+    struct Report<'a> {
+        #[descriptor(output_table)]
+        adults: Vec<&'a User>,
+    }
+
+    let users = [
+        User {
+            name: "Adrien".to_string(),
+            age: 32,
+        },
+        User {
+            name: "Jane".to_string(),
+            age: 17,
+        },
+    ];
+    let report = Report {
+        adults: users.iter().filter(|user| user.age >= 18).collect(),
+    };
+
+    let description = descriptor::object_describe_to_string(&report).unwrap();
+    assert_eq!(
+        r#"
+Adults:
+  NAME     AGE
+  Adrien   32
+"#,
+        description
+    );
+
+    let description =
+        table_describe_with_header_to_string(&report.adults, &["age".to_string()]).unwrap();
+    assert_eq!(
+        r#"
+AGE
+32
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_rename_header_fn() {
+    #[derive(Descriptor)]
+    struct Car {
+        #[descriptor(rename_header_fn = translate)]
+        brand: String,
+        #[descriptor(rename_header_fn = translate)]
+        top_speed: u32,
+        #[descriptor(rename_header = "Seats")]
+        seat: u8,
+    }
+
+    fn translate(field: &str) -> String {
+        format!("T_{}", field.to_uppercase())
+    }
+
+    let cars = vec![Car {
+        brand: "Tesla".to_string(),
+        top_speed: 261,
+        seat: 5,
+    }];
+    let description = table_describe_to_string(&cars).unwrap();
+    assert_eq!(
+        r#"
+T_BRAND T_TOP_SPEED Seats
+Tesla   261         5
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_spread() {
+    #[derive(Descriptor)]
+    struct Car {
+        brand: String,
+        #[descriptor(spread = 3)]
+        tags: Vec<String>,
+    }
+
+    let cars = vec![Car {
+        brand: "Tesla".to_string(),
+        tags: vec!["electric".to_string(), "fast".to_string()],
+    }];
+    let description = table_describe_to_string(&cars).unwrap();
+    assert_eq!(
+        r#"
+BRAND TAGS_1   TAGS_2 TAGS_3
+Tesla electric fast   ~
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_unicode_width() {
+    #[derive(Descriptor)]
+    struct City {
+        #[descriptor(rename_header = "名前")]
+        name: String,
+        #[descriptor(rename_header = "Größe")]
+        size: u32,
+        country: String,
+    }
+
+    let cities = vec![
+        City {
+            name: "Re\u{301}union".to_string(),
+            size: 2512,
+            country: "France".to_string(),
+        },
+        City {
+            name: "東京".to_string(),
+            size: 2194,
+            country: "Japan".to_string(),
+        },
+    ];
+    let description = table_describe_to_string(&cities).unwrap();
+    assert_eq!(
+        "
+名前    Größe COUNTRY
+Re\u{301}union 2512  France
+東京    2194  Japan
+",
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_recursive_type() {
+    #[derive(Descriptor)]
+    struct Node {
+        name: String,
+        child: Option<Box<Node>>,
+    }
+
+    let nodes = vec![
+        Node {
+            name: "root".to_string(),
+            child: Some(Box::new(Node {
+                name: "leaf".to_string(),
+                child: None,
+            })),
+        },
+        Node {
+            name: "alone".to_string(),
+            child: None,
+        },
+    ];
+    assert_eq!(
+        vec!["name".to_string(), "child".to_string()],
+        headers_of::<Node>()
+    );
+    let description = table_describe_to_string(&nodes).unwrap();
+    assert_eq!(
+        r#"
+NAME  CHILD
+root  name=leaf child=~
+alone ~
+"#,
+        no_color_and_line_return(description)
+    );
+}
+
+#[test]
+fn test_table_machine_key() {
+    #[derive(Descriptor)]
+    struct User {
+        #[descriptor(key = "first_name", rename_header = "FIRST")]
+        name: String,
+        #[descriptor(key = "home")]
+        address: Address,
+    }
+
+    #[derive(Descriptor)]
+    struct Address {
+        #[descriptor(key = "zip")]
+        postal_code: String,
+        town: String,
+    }
+
+    // Machine keys do not change the table headers
+    let table = table_describe_to_string(&[User {
+        name: "Adrien".to_string(),
+        address: Address {
+            postal_code: "75001".to_string(),
+            town: "Paris".to_string(),
+        },
+    }])
+    .unwrap();
+    assert_eq!(
+        r#"
+FIRST  ADDRESS.POSTAL_CODE ADDRESS.TOWN
+Adrien 75001               Paris
+"#,
+        no_color_and_line_return(table)
+    );
+}